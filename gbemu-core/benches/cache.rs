@@ -0,0 +1,80 @@
+//! Emulation speed with each mode of the decoded-instruction cache
+//! Run with `cargo bench -p gbemu-core`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use gbemu_core::hardware::cache::CacheMode;
+use gbemu_core::{Memory, ROMAccess, System};
+
+/// Loop reading and writing WRAM, a typical mix of loads, arithmetic and jumps
+const PROGRAM: [u8; 13] = [
+    0x21, 0x00, 0xC1, // .loop: ld hl, $C100
+    0x06, 0x40, // ld b, $40
+    0x7E, // .inner: ld a, [hl]
+    0x80, // add a, b
+    0x22, // ld [hl+], a
+    0x05, // dec b
+    0x20, 0xFA, // jr nz, .inner
+    0x18, 0xF3, // jr .loop
+];
+
+/// A ROM running `PROGRAM` from 0x150, or from WRAM at 0xC000 once it copied it there
+fn rom(in_wram: bool) -> Vec<u8> {
+    let mut rom = vec![0; 0x8000];
+    // nop; jp $0150
+    rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]);
+    rom[0x134..0x138].copy_from_slice(b"BNCH");
+    let mut checksum: u8 = 0;
+    for byte in &rom[0x134..0x14D] {
+        checksum = checksum.wrapping_sub(*byte).wrapping_sub(1);
+    }
+    rom[0x14D] = checksum;
+    let copy = [
+        0x21,
+        0x00,
+        0x02, // ld hl, $0200
+        0x11,
+        0x00,
+        0xC0, // ld de, $C000
+        0x06,
+        PROGRAM.len() as u8, // ld b, len
+        0x2A,                // .copy: ld a, [hl+]
+        0x12,                // ld [de], a
+        0x13,                // inc de
+        0x05,                // dec b
+        0x20,
+        0xFA, // jr nz, .copy
+        0xC3,
+        0x00,
+        0xC0, // jp $C000
+    ];
+    if in_wram {
+        rom[0x150..0x150 + copy.len()].copy_from_slice(&copy);
+        rom[0x200..0x200 + PROGRAM.len()].copy_from_slice(&PROGRAM);
+    } else {
+        rom[0x150..0x150 + PROGRAM.len()].copy_from_slice(&PROGRAM);
+    }
+    rom
+}
+
+fn system(in_wram: bool, mode: CacheMode) -> System {
+    let mut memory = Memory::new();
+    memory.set_instruction_cache(mode);
+    memory
+        .load_rom_data(&rom(in_wram))
+        .expect("ERROR: Invalid benchmark ROM");
+    System::new(memory)
+}
+
+fn cache(c: &mut Criterion) {
+    for (name, in_wram) in [("rom", false), ("wram", true)] {
+        let mut group = c.benchmark_group(format!("cache/{}", name));
+        for mode in [CacheMode::Off, CacheMode::Rom, CacheMode::RomAndRam] {
+            let mut system = system(in_wram, mode);
+            group.bench_function(format!("{:?}", mode), |b| b.iter(|| system.run_frame()));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, cache);
+criterion_main!(benches);
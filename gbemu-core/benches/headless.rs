@@ -0,0 +1,52 @@
+//! Headless emulation speed, the goal is several hundred times real time
+//! One iteration emulates a second of Game Boy time, so real time is one second per iteration
+//! Run with `cargo bench -p gbemu-core --bench headless`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use gbemu_core::{Memory, ROMAccess, System, FRAME_RATE};
+
+/// Busy loop with the screen and sound on as left by the boot ROM, keeping every component
+/// scheduled
+const PROGRAM: [u8; 9] = [
+    0x21, 0x00, 0xC0, // .loop: ld hl, $C000
+    0x34, // .inner: inc [hl]
+    0x2C, // inc l
+    0x20, 0xFC, // jr nz, .inner
+    0x18, 0xF7, // jr .loop
+];
+
+fn system() -> System {
+    let mut rom = vec![0; 0x8000];
+    // nop; jp $0150
+    rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]);
+    rom[0x134..0x138].copy_from_slice(b"BNCH");
+    let mut checksum: u8 = 0;
+    for byte in &rom[0x134..0x14D] {
+        checksum = checksum.wrapping_sub(*byte).wrapping_sub(1);
+    }
+    rom[0x14D] = checksum;
+    rom[0x150..0x150 + PROGRAM.len()].copy_from_slice(&PROGRAM);
+    let mut memory = Memory::new();
+    memory
+        .load_rom_data(&rom)
+        .expect("ERROR: Invalid benchmark ROM");
+    System::new(memory)
+}
+
+fn headless(c: &mut Criterion) {
+    let mut system = system();
+    let frames = FRAME_RATE.round() as u32;
+    let mut group = c.benchmark_group("headless");
+    group.sample_size(10);
+    group.bench_function("1s", |b| {
+        b.iter(|| {
+            for _ in 0..frames {
+                system.run_frame();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, headless);
+criterion_main!(benches);
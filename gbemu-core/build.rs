@@ -0,0 +1,299 @@
+//! Generates the opcode tables and instruction decoders of the CPU from
+//! resources/opcode/opcodes.json
+//! opcodes.json is from https://gbdev.io/gb-opcodes/Opcodes.json and parsed with serde_json
+//! Every table is an array indexed by opcode, written to opcodes.rs in OUT_DIR and included by
+//! src/hardware/cpu/opcodes.rs. The decoders map every opcode to its `Instruction`, written to
+//! instructions.rs in OUT_DIR and included by src/hardware/cpu/instruction.rs.
+
+use serde_json::{Map, Value};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const SOURCE: &str = "resources/opcode/opcodes.json";
+
+/// Accessors panicking with the location of a malformed opcodes.json
+trait Field {
+    fn field(&self, key: &str) -> &Value;
+    fn str(&self) -> &str;
+    fn bool(&self) -> bool;
+    fn number(&self) -> u64;
+    fn array(&self) -> &[Value];
+    fn object(&self) -> &Map<String, Value>;
+}
+
+impl Field for Value {
+    fn field(&self, key: &str) -> &Value {
+        self.get(key)
+            .unwrap_or_else(|| panic!("ERROR: Missing field {} in {}", key, SOURCE))
+    }
+
+    fn str(&self) -> &str {
+        self.as_str()
+            .unwrap_or_else(|| panic!("ERROR: Expected a string in {}", SOURCE))
+    }
+
+    fn bool(&self) -> bool {
+        self.as_bool()
+            .unwrap_or_else(|| panic!("ERROR: Expected a boolean in {}", SOURCE))
+    }
+
+    fn number(&self) -> u64 {
+        self.as_u64()
+            .unwrap_or_else(|| panic!("ERROR: Expected a number in {}", SOURCE))
+    }
+
+    fn array(&self) -> &[Value] {
+        self.as_array()
+            .unwrap_or_else(|| panic!("ERROR: Expected an array in {}", SOURCE))
+    }
+
+    fn object(&self) -> &Map<String, Value> {
+        self.as_object()
+            .unwrap_or_else(|| panic!("ERROR: Expected an object in {}", SOURCE))
+    }
+}
+
+/// Write a table of 256 opcodes named `name` from the JSON object `table`
+fn write_table(out: &mut String, name: &str, table: &Value) {
+    let entries = table.object();
+    assert_eq!(entries.len(), 256, "ERROR: {} needs 256 opcodes", name);
+    writeln!(out, "pub static {}: [Opcode; 256] = [", name).unwrap();
+    for (index, (key, opcode)) in entries.iter().enumerate() {
+        assert_eq!(
+            u8::from_str_radix(key.trim_start_matches("0x"), 16).ok(),
+            Some(index as u8),
+            "ERROR: Opcodes in {} must be in order",
+            name
+        );
+        let cycles = opcode.field("cycles").array();
+        let taken = cycles[0].number();
+        let not_taken = cycles.get(1).map_or(taken, Value::number);
+        writeln!(out, "    Opcode {{").unwrap();
+        writeln!(
+            out,
+            "        mnemonic: {:?},",
+            opcode.field("mnemonic").str()
+        )
+        .unwrap();
+        writeln!(out, "        bytes: {},", opcode.field("bytes").number()).unwrap();
+        writeln!(out, "        cycles: [{}, {}],", taken, not_taken).unwrap();
+        writeln!(out, "        operands: [").unwrap();
+        let operands = opcode.field("operands").array();
+        assert!(
+            operands.len() <= 3,
+            "ERROR: Opcode {} has too many operands",
+            key
+        );
+        for operand in operands {
+            let flag = |key| operand.get(key).is_some_and(Value::bool);
+            writeln!(
+                out,
+                "            Operand {{ name: {:?}, bytes: {}, immediate: {}, increment: {}, decrement: {} }},",
+                operand.field("name").str(),
+                operand.get("bytes").map_or(0, Value::number),
+                operand.field("immediate").bool(),
+                flag("increment"),
+                flag("decrement"),
+            )
+            .unwrap();
+        }
+        for _ in operands.len()..3 {
+            writeln!(out, "            NULL_OPERAND,").unwrap();
+        }
+        writeln!(out, "        ],").unwrap();
+        writeln!(
+            out,
+            "        immediate: {},",
+            opcode.field("immediate").bool()
+        )
+        .unwrap();
+        let flags = opcode.field("flags");
+        writeln!(
+            out,
+            "        flags: Flags {{ z: {:?}, n: {:?}, h: {:?}, c: {:?} }},",
+            flags.field("Z").str(),
+            flags.field("N").str(),
+            flags.field("H").str(),
+            flags.field("C").str(),
+        )
+        .unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Operand of an opcode as listed in opcodes.json
+struct Operand<'a> {
+    name: &'a str,
+    immediate: bool,
+    increment: bool,
+    decrement: bool,
+}
+
+impl Operand<'_> {
+    fn parse(json: &Value) -> Operand<'_> {
+        Operand {
+            name: json.field("name").str(),
+            immediate: json.field("immediate").bool(),
+            increment: json.get("increment").is_some_and(Value::bool),
+            decrement: json.get("decrement").is_some_and(Value::bool),
+        }
+    }
+
+    fn is_r16(&self) -> bool {
+        self.immediate && matches!(self.name, "AF" | "BC" | "DE" | "HL" | "SP")
+    }
+
+    fn r16(&self) -> String {
+        format!("Reg16::{}", self.name)
+    }
+
+    fn cond(&self) -> String {
+        format!("Some(Cond::{})", self.name)
+    }
+
+    /// Register or memory operand, immediate addresses are fetched
+    fn target(&self) -> String {
+        if self.immediate {
+            return format!("Target::Reg(Reg8::{})", self.name);
+        }
+        if self.name == "HL" && !self.increment && !self.decrement {
+            return "Target::HL".to_string();
+        }
+        let ptr = match self.name {
+            "HL" if self.increment => "Ptr::HLI".to_string(),
+            "HL" if self.decrement => "Ptr::HLD".to_string(),
+            "BC" | "DE" => format!("Ptr::{}", self.name),
+            "C" => "Ptr::HighC".to_string(),
+            "a8" => "Ptr::High(fetch())".to_string(),
+            "a16" => format!("Ptr::Abs({})", FETCH_16),
+            name => panic!("ERROR: Unknown memory operand {}", name),
+        };
+        format!("Target::Mem({})", ptr)
+    }
+}
+
+/// Fetch a little-endian 16-bit immediate
+const FETCH_16: &str = "u16::from_le_bytes([fetch(), fetch()])";
+
+/// Name of the variant of an instruction without operands, NOP is Nop
+fn variant(mnemonic: &str) -> String {
+    mnemonic[..1].to_string() + &mnemonic[1..].to_lowercase()
+}
+
+/// Expression building the `Instruction` of an opcode
+fn instruction(opcode: u8, mnemonic: &str, operands: &[Operand]) -> String {
+    let imm16 = format!("Imm16({})", FETCH_16);
+    let rel8 = "Rel8(fetch() as i8)";
+    let cond = |operand: &Operand| operand.cond();
+    let instruction = match (mnemonic, operands) {
+        ("PREFIX", _) => return "Instruction::cb_prefixed(fetch())".to_string(),
+        (mnemonic, _) if mnemonic.starts_with("ILLEGAL") => format!("Illegal({:#04X})", opcode),
+        ("RET", []) => "Ret(None)".to_string(),
+        // The byte after STOP is skipped by the CPU rather than fetched
+        ("STOP", _) | (_, []) => variant(mnemonic),
+        ("LD", [_, _, _]) => format!("LdHlSp({})", rel8),
+        ("LD", [addr, sp]) if sp.name == "SP" => {
+            assert_eq!(addr.name, "a16");
+            format!("LdAbsSp({})", imm16)
+        }
+        ("LD", [sp, hl]) if sp.name == "SP" && hl.name == "HL" => "LdSpHl".to_string(),
+        ("LD", [reg, _]) if reg.is_r16() => format!("Ld16({}, {})", reg.r16(), imm16),
+        ("LD" | "LDH", [dst, src]) if src.name == "n8" => {
+            format!("LdImm({}, Imm8(fetch()))", dst.target())
+        }
+        ("LD" | "LDH", [dst, src]) => format!("Ld({}, {})", dst.target(), src.target()),
+        ("PUSH" | "POP", [reg]) => format!("{}({})", variant(mnemonic), reg.r16()),
+        ("ADD", [hl, reg]) if hl.name == "HL" => format!("AddHl({})", reg.r16()),
+        ("ADD", [sp, _]) if sp.name == "SP" => format!("AddSp({})", rel8),
+        ("ADD" | "ADC" | "SUB" | "SBC" | "AND" | "XOR" | "OR" | "CP", [_, src]) => {
+            let op = format!("AluOp::{}", variant(mnemonic));
+            if src.name == "n8" {
+                format!("AluImm({}, Imm8(fetch()))", op)
+            } else {
+                format!("Alu({}, {})", op, src.target())
+            }
+        }
+        ("INC" | "DEC", [reg]) if reg.is_r16() => {
+            format!("{}16({})", variant(mnemonic), reg.r16())
+        }
+        ("INC" | "DEC", [target]) => format!("{}({})", variant(mnemonic), target.target()),
+        ("JP", [hl]) if hl.name == "HL" => "JpHl".to_string(),
+        ("JP" | "CALL", [_]) => format!("{}(None, {})", variant(mnemonic), imm16),
+        ("JP" | "CALL", [c, _]) => format!("{}({}, {})", variant(mnemonic), cond(c), imm16),
+        ("JR", [_]) => format!("Jr(None, {})", rel8),
+        ("JR", [c, _]) => format!("Jr({}, {})", cond(c), rel8),
+        ("RET", [c]) => format!("Ret({})", cond(c)),
+        ("RST", [vector]) => format!("Rst(0x{})", vector.name.trim_start_matches('$')),
+        ("RLC" | "RRC" | "RL" | "RR" | "SLA" | "SRA" | "SWAP" | "SRL", [target]) => {
+            format!("Shift(ShiftOp::{}, {})", variant(mnemonic), target.target())
+        }
+        ("BIT" | "RES" | "SET", [n, target]) => {
+            format!("{}({}, {})", variant(mnemonic), n.name, target.target())
+        }
+        _ => panic!("ERROR: Unknown instruction {} at {:#04X}", mnemonic, opcode),
+    };
+    format!("Instruction::{}", instruction)
+}
+
+/// Write a decoder function named `name` matching every opcode of the JSON object `table`
+fn write_decoder(out: &mut String, name: &str, signature: &str, table: &Value) {
+    writeln!(out, "    pub fn {}({}) -> Instruction {{", name, signature).unwrap();
+    writeln!(out, "        match opcode {{").unwrap();
+    for (index, (_, opcode)) in table.object().iter().enumerate() {
+        let operands: Vec<Operand> = opcode
+            .field("operands")
+            .array()
+            .iter()
+            .map(Operand::parse)
+            .collect();
+        let mnemonic = opcode.field("mnemonic").str();
+        let instruction = instruction(index as u8, mnemonic, &operands);
+        writeln!(out, "            {:#04X} => {},", index, instruction).unwrap();
+    }
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    let data =
+        fs::read(SOURCE).unwrap_or_else(|e| panic!("ERROR: Could not read {}: {}", SOURCE, e));
+    let json: Value = serde_json::from_slice(&data)
+        .unwrap_or_else(|e| panic!("ERROR: Could not parse {}: {}", SOURCE, e));
+    let mut out = String::new();
+    write_table(&mut out, "UNPREFIXED_OPCODES", json.field("unprefixed"));
+    write_table(&mut out, "CB_PREFIXED_OPCODES", json.field("cbprefixed"));
+    write(&out, "opcodes.rs");
+
+    let mut out = String::new();
+    writeln!(out, "impl Instruction {{").unwrap();
+    writeln!(
+        out,
+        "    /// Decode an unprefixed opcode, reading its immediate operands with `fetch`"
+    )
+    .unwrap();
+    write_decoder(
+        &mut out,
+        "unprefixed",
+        "opcode: u8, fetch: &mut impl FnMut() -> u8",
+        json.field("unprefixed"),
+    );
+    writeln!(out, "    /// Decode a CB prefixed opcode").unwrap();
+    write_decoder(
+        &mut out,
+        "cb_prefixed",
+        "opcode: u8",
+        json.field("cbprefixed"),
+    );
+    writeln!(out, "}}").unwrap();
+    write(&out, "instructions.rs");
+}
+
+/// Write generated code to `name` in OUT_DIR
+fn write(code: &str, name: &str) {
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join(name);
+    fs::write(&path, code).unwrap_or_else(|e| panic!("ERROR: Could not write {:?}: {}", path, e));
+}
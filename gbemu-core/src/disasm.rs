@@ -0,0 +1,603 @@
+use super::hardware::cpu::instruction::{Cond, Imm16, Imm8, Instruction, Ptr, Rel8, Target};
+use super::hardware::cpu::opcodes::{cb_prefixed_opcode_get, unprefixed_opcode_get};
+use super::hardware::cpu::reg::Reg8;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Size of a ROM bank, bank 0 is mapped at [0x0000..0x3FFF] and the others at [0x4000..0x7FFF]
+pub const BANK_SIZE: usize = 0x4000;
+
+/// Where execution can start without being jumped to: the cartridge entry point and the
+/// interrupt vectors
+pub const ENTRY_POINTS: [(u16, &str); 6] = [
+    (0x0100, "Entry"),
+    (0x0040, "VBlankInterrupt"),
+    (0x0048, "StatInterrupt"),
+    (0x0050, "TimerInterrupt"),
+    (0x0058, "SerialInterrupt"),
+    (0x0060, "JoypadInterrupt"),
+];
+
+/// Bytes per `db` line of data
+const DATA_LINE: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// Why an address is labelled, a label named after one reason wins over the ones before it
+enum Reference {
+    Jump,
+    Call,
+    Entry(&'static str),
+}
+
+/// Recursive disassembly of a ROM
+/// Control flow is followed from the entry points through jumps, calls and RSTs. Bytes that no
+/// path reaches are data, and every jump and call target that starts an instruction is labelled.
+/// Banks are walked separately: code in a switchable bank reaches its own bank at
+/// [0x4000..0x7FFF] and bank 0 below. Code in bank 0 reaches the bank it last selected with
+/// `ld a, n` followed by a store of A to [0x2000..0x3FFF], the bank select register of the
+/// memory bank controllers. Targets in [0x4000..0x7FFF] of bank 0 code that did not select a bank
+/// are left as addresses, unless the ROM has a single switchable bank.
+/// Displayed as RGBDS assembly, with one section per bank, which reassembles into the same ROM.
+pub struct Disassembly {
+    rom: Vec<u8>,
+    /// Instructions and their length by the ROM offset they start at
+    code: BTreeMap<usize, (Instruction, usize)>,
+    /// Labels by the ROM offset they are at
+    labels: BTreeMap<usize, String>,
+    /// ROM offset jumped or called to by the instruction at a ROM offset, when it is known
+    targets: BTreeMap<usize, usize>,
+}
+
+impl Disassembly {
+    /// Disassemble a ROM
+    pub fn new(rom: &[u8]) -> Disassembly {
+        let mut disassembly = Disassembly {
+            rom: rom.to_vec(),
+            code: BTreeMap::new(),
+            labels: BTreeMap::new(),
+            targets: BTreeMap::new(),
+        };
+        disassembly.trace();
+        disassembly
+    }
+
+    /// Number of banks, the last one may be partial
+    pub fn banks(&self) -> usize {
+        self.rom.len().div_ceil(BANK_SIZE)
+    }
+
+    /// Instruction starting at an address of a bank, None if it is data
+    pub fn instruction(&self, bank: usize, addr: u16) -> Option<Instruction> {
+        self.offset(bank, addr)
+            .and_then(|offset| self.code.get(&offset))
+            .map(|&(instruction, _)| instruction)
+    }
+
+    /// Label at an address of a bank
+    pub fn label(&self, bank: usize, addr: u16) -> Option<&str> {
+        self.offset(bank, addr)
+            .and_then(|offset| self.labels.get(&offset))
+            .map(String::as_str)
+    }
+
+    /// ROM offset of an address as seen by code in `bank`, None if it is not known to be in ROM
+    fn offset(&self, bank: usize, addr: u16) -> Option<usize> {
+        let offset = match addr {
+            0x0000..=0x3FFF => addr as usize,
+            0x4000..=0x7FFF if bank > 0 => bank * BANK_SIZE + addr as usize - 0x4000,
+            0x4000..=0x7FFF if self.banks() <= 2 => addr as usize,
+            _ => return None,
+        };
+        (offset < self.rom.len()).then_some(offset)
+    }
+
+    /// Bank and address of a ROM offset
+    fn address(offset: usize) -> (usize, u16) {
+        let bank = offset / BANK_SIZE;
+        let base = if bank == 0 { 0 } else { 0x4000 };
+        (bank, (base + offset % BANK_SIZE) as u16)
+    }
+
+    /// Decode the instruction at a ROM offset, None if it runs past the end of its bank
+    fn decode(&self, offset: usize) -> Option<(Instruction, usize)> {
+        let opcode = self.rom[offset];
+        let bytes = match opcode {
+            0xCB => cb_prefixed_opcode_get(*self.rom.get(offset + 1)?).bytes,
+            _ => unprefixed_opcode_get(opcode).bytes,
+        } as usize;
+        let end = ((offset / BANK_SIZE + 1) * BANK_SIZE).min(self.rom.len());
+        if offset + bytes > end {
+            return None;
+        }
+        let mut next = offset + 1;
+        let instruction = Instruction::unprefixed(opcode, &mut || {
+            next += 1;
+            self.rom[next - 1]
+        });
+        Some((instruction, bytes))
+    }
+
+    /// Address jumped or called to by an instruction at `addr`, and whether execution may also
+    /// continue after it
+    fn flow(instruction: Instruction, addr: u16, bytes: usize) -> (Option<(u16, Reference)>, bool) {
+        let next = addr.wrapping_add(bytes as u16);
+        match instruction {
+            Instruction::Jp(cond, Imm16(target)) => {
+                (Some((target, Reference::Jump)), cond.is_some())
+            }
+            Instruction::Jr(cond, Rel8(offset)) => (
+                Some((next.wrapping_add(offset as u16), Reference::Jump)),
+                cond.is_some(),
+            ),
+            Instruction::Call(_, Imm16(target)) => (Some((target, Reference::Call)), true),
+            Instruction::Rst(vector) => (Some((vector as u16, Reference::Call)), true),
+            Instruction::Ret(None) | Instruction::Reti | Instruction::JpHl => (None, false),
+            Instruction::Illegal(_) => (None, false),
+            _ => (None, true),
+        }
+    }
+
+    /// Bank selected by bank 0 code once it executed `instruction`, with `a` the value of A
+    /// before it if known. Returns the new value of A and the selected bank.
+    /// Only `ld a, n` sets A, and only stores of A keep it.
+    fn select_bank(
+        &self,
+        instruction: Instruction,
+        a: Option<u8>,
+        selected: Option<usize>,
+    ) -> (Option<u8>, Option<usize>) {
+        match instruction {
+            Instruction::LdImm(Target::Reg(Reg8::A), Imm8(val)) => (Some(val), selected),
+            Instruction::Ld(Target::Mem(Ptr::Abs(0x2000..=0x3FFF)), Target::Reg(Reg8::A)) => {
+                // Selecting bank 0 selects bank 1
+                let bank = a.map(|a| (a as usize).max(1));
+                (a, bank.filter(|&bank| bank < self.banks()))
+            }
+            Instruction::Ld(Target::Mem(_) | Target::HL, Target::Reg(Reg8::A)) => (a, selected),
+            _ => (None, selected),
+        }
+    }
+
+    /// Follow control flow from the entry points, recording instructions and references
+    /// Paths through bank 0 carry the value of A and the bank they selected, and are walked
+    /// again when they reach decoded code with a state not seen there yet.
+    fn trace(&mut self) {
+        let mut references = BTreeMap::new();
+        // Bytes covered by an instruction
+        let mut covered = vec![false; self.rom.len()];
+        let mut visited = BTreeSet::new();
+        let mut pending = Vec::new();
+        for (addr, name) in ENTRY_POINTS {
+            if let Some(offset) = self.offset(0, addr) {
+                references.insert(offset, Reference::Entry(name));
+                pending.push((offset, None, None));
+            }
+        }
+        while let Some((offset, a, selected)) = pending.pop() {
+            if !visited.insert((offset, a, selected)) {
+                continue;
+            }
+            let (instruction, bytes) = match self.code.get(&offset) {
+                Some(&decoded) => decoded,
+                // Inside another instruction
+                None if covered[offset] => continue,
+                None => {
+                    let Some((instruction, bytes)) = self.decode(offset) else {
+                        continue;
+                    };
+                    if covered[offset..offset + bytes]
+                        .iter()
+                        .any(|&covered| covered)
+                    {
+                        continue;
+                    }
+                    covered[offset..offset + bytes].fill(true);
+                    self.code.insert(offset, (instruction, bytes));
+                    (instruction, bytes)
+                }
+            };
+
+            let (bank, addr) = Disassembly::address(offset);
+            let (a, selected) = if bank == 0 {
+                self.select_bank(instruction, a, selected)
+            } else {
+                (None, None)
+            };
+            let mapped = if bank == 0 {
+                selected.unwrap_or(0)
+            } else {
+                bank
+            };
+            let (target, continues) = Disassembly::flow(instruction, addr, bytes);
+            if let Some((target, reference)) = target {
+                if let Some(target) = self.offset(mapped, target) {
+                    let known = references.entry(target).or_insert(reference);
+                    if reference > *known {
+                        *known = reference;
+                    }
+                    self.targets.entry(offset).or_insert(target);
+                    if target < BANK_SIZE {
+                        pending.push((target, a, selected));
+                    } else {
+                        pending.push((target, None, None));
+                    }
+                }
+            }
+            // The callee may change A
+            let a = match target {
+                Some((_, Reference::Call)) => None,
+                _ => a,
+            };
+            // Falling through into the next bank does not happen with banks switched
+            if continues && (offset + bytes) % BANK_SIZE != 0 && offset + bytes < self.rom.len() {
+                pending.push((offset + bytes, a, selected));
+            }
+        }
+        // Targets inside other instructions cannot be labelled
+        for (offset, reference) in references {
+            if !self.code.contains_key(&offset) {
+                continue;
+            }
+            let (bank, addr) = Disassembly::address(offset);
+            let name = match reference {
+                Reference::Entry(name) => name.to_string(),
+                Reference::Call => format!("Call_{:03X}_{:04X}", bank, addr),
+                Reference::Jump => format!("Jump_{:03X}_{:04X}", bank, addr),
+            };
+            self.labels.insert(offset, name);
+        }
+    }
+
+    /// Label of the target of the instruction at a ROM offset, or the address it jumps to
+    fn target(&self, offset: usize, addr: u16) -> String {
+        match self
+            .targets
+            .get(&offset)
+            .and_then(|target| self.labels.get(target))
+        {
+            Some(label) => label.to_string(),
+            None => format!("${:04X}", addr),
+        }
+    }
+
+    /// RGBDS source of the instruction at a ROM offset
+    /// Encodings RGBDS would not reproduce are written as their bytes.
+    fn rgbds(&self, offset: usize, instruction: Instruction) -> String {
+        let (_, addr) = Disassembly::address(offset);
+        let cond = |cond: Option<Cond>| cond.map_or(String::new(), |cond| lower(cond) + ", ");
+        match instruction {
+            Instruction::Nop => "nop".to_string(),
+            // RGBDS always follows STOP with 0x00
+            Instruction::Stop if self.rom[offset + 1] == 0x00 => "stop".to_string(),
+            Instruction::Stop => format!("db $10, ${:02X}", self.rom[offset + 1]),
+            Instruction::Halt => "halt".to_string(),
+            Instruction::Di => "di".to_string(),
+            Instruction::Ei => "ei".to_string(),
+            Instruction::Ld(dst @ Target::Mem(Ptr::High(_) | Ptr::HighC), src)
+            | Instruction::Ld(dst, src @ Target::Mem(Ptr::High(_) | Ptr::HighC)) => {
+                format!("ldh {}, {}", operand(dst), operand(src))
+            }
+            Instruction::Ld(dst, src) => format!("ld {}, {}", operand(dst), operand(src)),
+            Instruction::LdImm(dst, Imm8(val)) => format!("ld {}, ${:02X}", operand(dst), val),
+            Instruction::Ld16(reg, Imm16(val)) => format!("ld {}, ${:04X}", lower(reg), val),
+            Instruction::LdSpHl => "ld sp, hl".to_string(),
+            Instruction::LdHlSp(Rel8(offset)) => format!("ld hl, sp{:+}", offset),
+            Instruction::LdAbsSp(Imm16(addr)) => format!("ld [${:04X}], sp", addr),
+            Instruction::Push(reg) => format!("push {}", lower(reg)),
+            Instruction::Pop(reg) => format!("pop {}", lower(reg)),
+            Instruction::Alu(op, src) => format!("{} a, {}", lower(op), operand(src)),
+            Instruction::AluImm(op, Imm8(val)) => format!("{} a, ${:02X}", lower(op), val),
+            Instruction::AddHl(reg) => format!("add hl, {}", lower(reg)),
+            Instruction::AddSp(Rel8(offset)) => format!("add sp, {}", offset),
+            Instruction::Inc(target) => format!("inc {}", operand(target)),
+            Instruction::Dec(target) => format!("dec {}", operand(target)),
+            Instruction::Inc16(reg) => format!("inc {}", lower(reg)),
+            Instruction::Dec16(reg) => format!("dec {}", lower(reg)),
+            Instruction::Daa => "daa".to_string(),
+            Instruction::Cpl => "cpl".to_string(),
+            Instruction::Scf => "scf".to_string(),
+            Instruction::Ccf => "ccf".to_string(),
+            Instruction::Rlca => "rlca".to_string(),
+            Instruction::Rrca => "rrca".to_string(),
+            Instruction::Rla => "rla".to_string(),
+            Instruction::Rra => "rra".to_string(),
+            Instruction::JpHl => "jp hl".to_string(),
+            Instruction::Jp(c, Imm16(target)) => {
+                format!("jp {}{}", cond(c), self.target(offset, target))
+            }
+            Instruction::Jr(c, Rel8(rel)) => {
+                // RGBDS computes the offset from the target without wrapping around
+                let target = addr as i32 + 2 + rel as i32;
+                if (0..=0xFFFF).contains(&target) {
+                    format!("jr {}{}", cond(c), self.target(offset, target as u16))
+                } else {
+                    format!("db ${:02X}, ${:02X}", self.rom[offset], rel as u8)
+                }
+            }
+            Instruction::Call(c, Imm16(target)) => {
+                format!("call {}{}", cond(c), self.target(offset, target))
+            }
+            Instruction::Ret(None) => "ret".to_string(),
+            Instruction::Ret(Some(c)) => format!("ret {}", lower(c)),
+            Instruction::Reti => "reti".to_string(),
+            Instruction::Rst(vector) => format!("rst ${:02X}", vector),
+            Instruction::Shift(op, target) => format!("{} {}", lower(op), operand(target)),
+            Instruction::Bit(n, target) => format!("bit {}, {}", n, operand(target)),
+            Instruction::Res(n, target) => format!("res {}, {}", n, operand(target)),
+            Instruction::Set(n, target) => format!("set {}, {}", n, operand(target)),
+            Instruction::Illegal(opcode) => format!("db ${:02X}", opcode),
+        }
+    }
+}
+
+/// RGBDS operand of an 8-bit instruction
+fn operand(target: Target) -> String {
+    match target {
+        Target::Reg(reg) => lower(reg),
+        Target::Mem(Ptr::BC) => "[bc]".to_string(),
+        Target::Mem(Ptr::DE) => "[de]".to_string(),
+        Target::HL => "[hl]".to_string(),
+        Target::Mem(Ptr::HLI) => "[hl+]".to_string(),
+        Target::Mem(Ptr::HLD) => "[hl-]".to_string(),
+        Target::Mem(Ptr::HighC) => "[c]".to_string(),
+        Target::Mem(Ptr::High(offset)) => format!("[${:04X}]", 0xFF00 | offset as u16),
+        Target::Mem(Ptr::Abs(addr)) => format!("[${:04X}]", addr),
+    }
+}
+
+/// Registers, conditions and operations are written lowercase
+fn lower(value: impl fmt::Debug) -> String {
+    format!("{:?}", value).to_lowercase()
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "; Disassembled by gbEMU, reassemble with RGBDS")?;
+        for bank in 0..self.banks() {
+            writeln!(f)?;
+            if bank == 0 {
+                writeln!(f, "SECTION \"ROM Bank $000\", ROM0[$0000]")?;
+            } else {
+                writeln!(
+                    f,
+                    "SECTION \"ROM Bank ${:03X}\", ROMX[$4000], BANK[${:X}]",
+                    bank, bank
+                )?;
+            }
+            let end = ((bank + 1) * BANK_SIZE).min(self.rom.len());
+            let mut offset = bank * BANK_SIZE;
+            while offset < end {
+                if let Some(label) = self.labels.get(&offset) {
+                    writeln!(f)?;
+                    writeln!(f, "{}:", label)?;
+                }
+                if let Some(&(instruction, bytes)) = self.code.get(&offset) {
+                    writeln!(f, "    {}", self.rgbds(offset, instruction))?;
+                    offset += bytes;
+                    continue;
+                }
+                // Data runs until the next instruction, up to a line
+                let data_end = self
+                    .code
+                    .range(offset..end)
+                    .next()
+                    .map_or(end, |(&start, _)| start)
+                    .min(offset + DATA_LINE);
+                let bytes: Vec<String> = self.rom[offset..data_end]
+                    .iter()
+                    .map(|byte| format!("${:02X}", byte))
+                    .collect();
+                writeln!(f, "    db {}", bytes.join(", "))?;
+                offset = data_end;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Four banks: bank 0 selects bank 2 and calls into it, then selects bank 3 and jumps
+    /// into it. The other bytes at those addresses look like code but are never reached.
+    fn banked_rom() -> Vec<u8> {
+        let mut rom = vec![0; 4 * BANK_SIZE];
+        // nop; jp $0150
+        rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]);
+        rom[0x150..0x163].copy_from_slice(&[
+            0x3E, 0x02, // ld a, $02
+            0xEA, 0x00, 0x20, // ld [$2000], a
+            0xCD, 0x00, 0x40, // call $4000
+            0x3E, 0x03, // ld a, $03
+            0xEA, 0x00, 0x21, // ld [$2100], a
+            0xC3, 0x10, 0x40, // jp $4010
+            0xC3, 0x00, 0x40, // jp $4000, never reached
+        ]);
+        rom[BANK_SIZE..BANK_SIZE + 3].copy_from_slice(&[0xC3, 0x00, 0x00]);
+        rom[2 * BANK_SIZE..2 * BANK_SIZE + 3].copy_from_slice(&[0x3E, 0x11, 0xC9]);
+        rom[2 * BANK_SIZE + 0x10..2 * BANK_SIZE + 0x13].copy_from_slice(&[0xCD, 0x00, 0x00]);
+        rom[3 * BANK_SIZE] = 0xC9;
+        rom[3 * BANK_SIZE + 0x10..3 * BANK_SIZE + 0x12].copy_from_slice(&[0x18, 0xFE]);
+        rom
+    }
+
+    /// Value of a `$` hexadecimal number or a label, 0 for labels not defined yet
+    fn value(operand: &str, labels: &BTreeMap<String, u16>) -> u16 {
+        match operand.strip_prefix('$') {
+            Some(hex) => u16::from_str_radix(hex, 16).unwrap(),
+            None => labels.get(operand).copied().unwrap_or(0),
+        }
+    }
+
+    /// Assemble the subset of RGBDS the disassembly of `banked_rom` is written in, the first
+    /// pass collects the labels and the second one writes the ROM
+    fn assemble(source: &str) -> Vec<u8> {
+        let mut labels = BTreeMap::new();
+        let mut rom = Vec::new();
+        for pass in 0..2 {
+            rom = vec![0; 4 * BANK_SIZE];
+            let mut offset = 0;
+            for line in source.lines().map(str::trim) {
+                let (_, addr) = Disassembly::address(offset);
+                if line.is_empty() || line.starts_with(';') {
+                    continue;
+                }
+                if line.starts_with("SECTION") {
+                    offset = line.split_once("BANK[$").map_or(0, |(_, bank)| {
+                        usize::from_str_radix(bank.trim_end_matches(']'), 16).unwrap() * BANK_SIZE
+                    });
+                    continue;
+                }
+                if let Some(label) = line.strip_suffix(':') {
+                    if pass == 0 {
+                        labels.insert(label.to_string(), addr);
+                    }
+                    continue;
+                }
+                let (mnemonic, operands) = line.split_once(' ').unwrap_or((line, ""));
+                let operands: Vec<&str> = operands.split(", ").collect();
+                let [low, high] = value(operands[0], &labels).to_le_bytes();
+                let bytes = match (mnemonic, operands.as_slice()) {
+                    ("db", bytes) => bytes
+                        .iter()
+                        .map(|byte| value(byte, &labels) as u8)
+                        .collect(),
+                    ("nop", _) => vec![0x00],
+                    ("ret", _) => vec![0xC9],
+                    ("ld", ["a", val]) => vec![0x3E, value(val, &labels) as u8],
+                    ("ld", [dst, "a"]) => {
+                        let [low, high] =
+                            value(dst.trim_matches(['[', ']']), &labels).to_le_bytes();
+                        vec![0xEA, low, high]
+                    }
+                    ("jp", _) => vec![0xC3, low, high],
+                    ("call", _) => vec![0xCD, low, high],
+                    ("jr", [target]) => {
+                        let rel = value(target, &labels).wrapping_sub(addr.wrapping_add(2));
+                        vec![0x18, rel as u8]
+                    }
+                    _ => panic!("ERROR: Unsupported line {}", line),
+                };
+                rom[offset..offset + bytes.len()].copy_from_slice(&bytes);
+                offset += bytes.len();
+            }
+        }
+        rom
+    }
+
+    #[test]
+    fn follows_bank_switches() {
+        let disassembly = Disassembly::new(&banked_rom());
+        assert_eq!(
+            disassembly.instruction(2, 0x4000),
+            Some(Instruction::LdImm(Target::Reg(Reg8::A), Imm8(0x11)))
+        );
+        assert_eq!(disassembly.label(2, 0x4000), Some("Call_002_4000"));
+        assert_eq!(
+            disassembly.instruction(3, 0x4010),
+            Some(Instruction::Jr(None, Rel8(-2)))
+        );
+        assert_eq!(disassembly.label(3, 0x4010), Some("Jump_003_4010"));
+        for (bank, addr) in [(1, 0x4000), (2, 0x4010), (3, 0x4000), (0, 0x0160)] {
+            assert_eq!(disassembly.instruction(bank, addr), None);
+        }
+        let source = disassembly.to_string();
+        assert!(source.contains("call Call_002_4000"));
+        assert!(source.contains("jp Jump_003_4010"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let rom = banked_rom();
+        assert!(assemble(&Disassembly::new(&rom).to_string()) == rom);
+    }
+
+    /// ROM with encodings RGBDS needs special care for at the entry point, followed by a jump to
+    /// a relative jump out of the address space and an illegal opcode at 0x0000
+    fn encodings_rom() -> Vec<u8> {
+        let mut rom = vec![0; 2 * BANK_SIZE];
+        rom[0x0000..0x0003].copy_from_slice(&[0x20, 0xFD, 0xD3]);
+        rom[0x0100..0x0118].copy_from_slice(&[
+            0x10, 0x00, // stop
+            0x10, 0x42, // stop followed by a byte other than 0x00
+            0xE2, // ldh [c], a
+            0xF2, // ldh a, [c]
+            0xE0, 0x44, // ldh [$FF44], a
+            0xEA, 0x44, 0xFF, // ld [$FF44], a
+            0xF8, 0x05, // ld hl, sp+5
+            0xF8, 0xFB, // ld hl, sp-5
+            0xE8, 0x03, // add sp, 3
+            0xE8, 0xFE, // add sp, -2
+            0xC3, 0x00, 0x00, // jp $0000
+            0xD3, // never reached
+            0x10, // never reached, STOP needs a second byte
+        ]);
+        rom
+    }
+
+    #[test]
+    fn rgbds_text() {
+        let source = Disassembly::new(&encodings_rom()).to_string();
+        let lines: Vec<&str> = source.lines().map(str::trim).collect();
+        let entry = lines.iter().position(|line| *line == "Entry:").unwrap();
+        assert_eq!(
+            lines[entry + 1..entry + 13],
+            [
+                "stop",
+                "db $10, $42",
+                "ldh [c], a",
+                "ldh a, [c]",
+                "ldh [$FF44], a",
+                "ld [$FF44], a",
+                "ld hl, sp+5",
+                "ld hl, sp-5",
+                "add sp, 3",
+                "add sp, -2",
+                "jp Jump_000_0000",
+                "db $D3, $10, $00, $00, $00, $00, $00, $00, $00, $00, $00, $00, $00, $00, $00, $00",
+            ]
+        );
+        // A jr before 0x0000 can not be written as jr in RGBDS
+        let jump = lines
+            .iter()
+            .position(|line| *line == "Jump_000_0000:")
+            .unwrap();
+        assert_eq!(lines[jump + 1..jump + 3], ["db $20, $FD", "db $D3"]);
+    }
+
+    #[test]
+    fn rgbds_reassembles_the_rom() {
+        use std::process::Command;
+        if Command::new("rgbasm").arg("--version").output().is_err()
+            || Command::new("rgblink").arg("--version").output().is_err()
+        {
+            eprintln!("rgbasm and rgblink are not installed, skipping");
+            return;
+        }
+        let dir = std::env::temp_dir().join(format!("gbemu-disasm-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, rom) in [("banked", banked_rom()), ("encodings", encodings_rom())] {
+            let source = dir.join(format!("{}.asm", name));
+            let object = dir.join(format!("{}.o", name));
+            let output = dir.join(format!("{}.gb", name));
+            std::fs::write(&source, Disassembly::new(&rom).to_string()).unwrap();
+            let status = Command::new("rgbasm")
+                .arg("-o")
+                .arg(&object)
+                .arg(&source)
+                .status()
+                .unwrap();
+            assert!(status.success(), "rgbasm failed on {}", name);
+            let status = Command::new("rgblink")
+                .arg("-o")
+                .arg(&output)
+                .arg(&object)
+                .status()
+                .unwrap();
+            assert!(status.success(), "rgblink failed on {}", name);
+            assert!(std::fs::read(&output).unwrap() == rom, "{} differs", name);
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
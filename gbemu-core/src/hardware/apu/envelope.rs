@@ -0,0 +1,77 @@
+use super::super::state::{Savestate, State};
+
+/// Volume envelope
+/// NRx2: VVVV DPPP
+/// BIT 0-2: Pace, the envelope is clocked every `pace` 64 Hz ticks (0 = disabled)
+/// BIT 3: Direction (0 = decrease, 1 = increase)
+/// BIT 4-7: Initial volume
+pub struct Envelope {
+    register: u8,
+    pub volume: u8,
+    timer: u8,
+}
+
+impl Envelope {
+    /// Create a new Envelope
+    pub fn new() -> Envelope {
+        Envelope {
+            register: 0,
+            volume: 0,
+            timer: 0,
+        }
+    }
+
+    /// Read NRx2
+    pub fn read(&self) -> u8 {
+        self.register
+    }
+
+    /// Write NRx2
+    pub fn write(&mut self, val: u8) {
+        self.register = val;
+    }
+
+    /// The channel DAC is on as long as the upper 5 bits of NRx2 are not all 0
+    pub fn dac_enabled(&self) -> bool {
+        self.register & 0xF8 != 0
+    }
+
+    /// Restart the envelope when the channel is triggered
+    pub fn trigger(&mut self) {
+        self.volume = self.register >> 4;
+        self.timer = self.register & 0b111;
+    }
+
+    /// Clock the envelope at 64 Hz
+    pub fn clock(&mut self) {
+        let pace = self.register & 0b111;
+        if pace == 0 {
+            return;
+        }
+        self.timer = self.timer.saturating_sub(1);
+        if self.timer == 0 {
+            self.timer = pace;
+            if self.register & 0b1000 != 0 {
+                if self.volume < 15 {
+                    self.volume += 1;
+                }
+            } else if self.volume > 0 {
+                self.volume -= 1;
+            }
+        }
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Envelope {
+        Envelope::new()
+    }
+}
+
+impl Savestate for Envelope {
+    fn state(&mut self, state: &mut State) {
+        state.u8(&mut self.register);
+        state.u8(&mut self.volume);
+        state.u8(&mut self.timer);
+    }
+}
@@ -0,0 +1,65 @@
+use super::super::state::{Savestate, State};
+
+/// Length counter
+/// Counts down at 256 Hz while enabled and turns its channel off when it reaches 0
+pub struct LengthCounter {
+    pub enabled: bool,
+    counter: u16,
+    max: u16,
+}
+
+impl LengthCounter {
+    /// Create a new LengthCounter for a channel with a maximum length of `max`
+    pub fn new(max: u16) -> LengthCounter {
+        LengthCounter {
+            enabled: false,
+            counter: 0,
+            max,
+        }
+    }
+
+    /// Load the length written to NRx1, the counter counts the remaining steps
+    pub fn load(&mut self, length: u16) {
+        self.counter = self.max - length;
+    }
+
+    /// Clock the counter, returns false if the channel must be turned off
+    pub fn clock(&mut self) -> bool {
+        if self.enabled && self.counter > 0 {
+            self.counter -= 1;
+            return self.counter != 0;
+        }
+        true
+    }
+
+    /// Handle a write to NRx4
+    /// `length_step` is set if the next frame sequencer step clocks length counters.
+    /// If it does not, enabling the counter clocks it once immediately, and a trigger that reloads
+    /// an expired counter loads one step less.
+    /// Returns false if the channel must be turned off
+    pub fn write_control(&mut self, enable: bool, trigger: bool, length_step: bool) -> bool {
+        let was_enabled = self.enabled;
+        self.enabled = enable;
+        let mut on = true;
+        if !length_step && enable && !was_enabled && self.counter > 0 {
+            self.counter -= 1;
+            on = self.counter != 0;
+        }
+        if trigger && self.counter == 0 {
+            self.counter = self.max;
+            if enable && !length_step {
+                self.counter -= 1;
+            }
+            on = true;
+        }
+        on || trigger
+    }
+}
+
+impl Savestate for LengthCounter {
+    fn state(&mut self, state: &mut State) {
+        state.bool(&mut self.enabled);
+        state.u16(&mut self.counter);
+        state.u16(&mut self.max);
+    }
+}
@@ -0,0 +1,561 @@
+pub mod envelope;
+pub mod length;
+pub mod noise;
+pub mod recorder;
+pub mod resampler;
+pub mod square;
+pub mod wav;
+pub mod wave;
+
+use self::noise::Noise;
+use self::recorder::Recorder;
+use self::resampler::BlipBuffer;
+use self::square::Square;
+use self::wave::Wave;
+use super::model::Model;
+use super::state::{Savestate, State};
+
+/// Audio registers
+/// Documented at: https://gbdev.io/pandocs/Audio_Registers.html
+/// Channel 1 [0xFF10..0xFF14], channel 2 [0xFF15..0xFF19], channel 3 [0xFF1A..0xFF1E],
+/// channel 4 [0xFF1F..0xFF23], with 0xFF15 and 0xFF1F unused
+pub const NR10: u16 = 0xFF10;
+pub const NR11: u16 = 0xFF11;
+pub const NR12: u16 = 0xFF12;
+pub const NR13: u16 = 0xFF13;
+pub const NR14: u16 = 0xFF14;
+pub const NR21: u16 = 0xFF16;
+pub const NR30: u16 = 0xFF1A;
+pub const NR31: u16 = 0xFF1B;
+pub const NR41: u16 = 0xFF20;
+pub const NR50: u16 = 0xFF24;
+pub const NR51: u16 = 0xFF25;
+pub const NR52: u16 = 0xFF26;
+pub const WAVE_RAM: u16 = 0xFF30;
+/// Last address of the audio registers and wave RAM
+pub const APU_END: u16 = 0xFF3F;
+
+/// Base addresses of the registers of each channel, NRx0
+const CH1: u16 = NR10;
+const CH2: u16 = NR21 - 1;
+const CH3: u16 = NR30;
+const CH4: u16 = NR41 - 1;
+
+/// T-cycles per second
+pub const CLOCK_RATE: u32 = 4194304;
+/// Output sample rate until one is set
+pub const DEFAULT_SAMPLE_RATE: u32 = 44100;
+/// Samples are dropped once twice this many seconds are waiting to be read, down to this many
+/// seconds, so nothing is dropped while a frontend keeps reading and headless runs drop in bulk
+const MAX_BUFFERED_SECONDS: usize = 1;
+
+/// Audio Processing Unit
+/// The four channels are clocked every T-cycle and stepped at 512 Hz by the frame sequencer,
+/// which is driven by the falling edge of bit 4 of DIV. The frame sequencer clocks the length
+/// counters on even steps, the sweep on steps 2 and 6 and the envelopes on step 7.
+/// The mixed output is resampled into a band-limited stereo stream at a configurable rate.
+pub struct Apu {
+    power: bool,
+    pub square1: Square,
+    pub square2: Square,
+    pub wave: Wave,
+    pub noise: Noise,
+    /// NR50: -LLL -RRR, master volume of the left and right output
+    nr50: u8,
+    /// NR51: panning, bits 4-7 enable channels 1-4 on the left, bits 0-3 on the right
+    nr51: u8,
+    /// Next step of the frame sequencer, 0-7
+    frame_step: u8,
+    cgb: bool,
+    left: BlipBuffer,
+    right: BlipBuffer,
+    recorder: Option<Recorder>,
+    /// Error that stopped the recording, returned by `stop_recording`
+    recording_error: Option<std::io::Error>,
+}
+
+impl Apu {
+    /// Create a new Apu producing samples at `sample_rate` Hz
+    pub fn new(sample_rate: u32) -> Apu {
+        Apu {
+            power: false,
+            square1: Square::new(true),
+            square2: Square::new(false),
+            wave: Wave::new(),
+            noise: Noise::new(),
+            nr50: 0,
+            nr51: 0,
+            frame_step: 0,
+            cgb: false,
+            left: BlipBuffer::new(CLOCK_RATE, sample_rate, false),
+            right: BlipBuffer::new(CLOCK_RATE, sample_rate, false),
+            recorder: None,
+            recording_error: None,
+        }
+    }
+
+    /// Change the output sample rate, dropping samples not read yet
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.left = BlipBuffer::new(CLOCK_RATE, sample_rate, self.cgb);
+        self.right = BlipBuffer::new(CLOCK_RATE, sample_rate, self.cgb);
+    }
+
+    /// Emulate the APU of a model
+    /// The CGB has a different high-pass filter and no wave RAM access restrictions
+    pub fn set_model(&mut self, model: Model) {
+        self.cgb = model.is_cgb();
+        self.wave.dmg = !self.cgb;
+        self.set_sample_rate(self.left.sample_rate());
+    }
+
+    /// Scale the output rate by `adjust`, see `resampler::DynamicRateControl`
+    pub fn set_rate_adjust(&mut self, adjust: f64) {
+        self.left.set_rate_adjust(adjust);
+        self.right.set_rate_adjust(adjust);
+    }
+
+    /// Record the output to a WAV file at `sample_rate` Hz
+    /// With `stems` set, each channel is also recorded to its own mono file
+    /// Recording stops at the first error writing the files, `stop_recording` returns it
+    pub fn start_recording(
+        &mut self,
+        path: &std::path::Path,
+        sample_rate: u32,
+        stems: bool,
+    ) -> std::io::Result<()> {
+        self.recorder = Some(Recorder::create(path, sample_rate, stems, self.cgb)?);
+        self.recording_error = None;
+        Ok(())
+    }
+
+    /// Stop recording and finish the WAV files
+    /// Returns the error that stopped the recording early, if any
+    pub fn stop_recording(&mut self) -> std::io::Result<()> {
+        if let Some(e) = self.recording_error.take() {
+            return Err(e);
+        }
+        match self.recorder.take() {
+            Some(mut recorder) => recorder.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Number of stereo samples ready to be read
+    pub fn available(&self) -> usize {
+        self.left.available()
+    }
+
+    /// Read interleaved left and right samples in the range -1.0..=1.0 into `out`
+    /// Returns the number of stereo samples read
+    pub fn read_samples(&mut self, out: &mut [f32]) -> usize {
+        let count = self.available().min(out.len() / 2);
+        let out = &mut out[..count * 2];
+        self.left.read(out.iter_mut().step_by(2));
+        self.right.read(out.iter_mut().skip(1).step_by(2));
+        count
+    }
+
+    /// Whether the next frame sequencer step clocks the length counters
+    fn length_step(&self) -> bool {
+        self.frame_step & 1 == 0
+    }
+
+    /// T-cycles until the timer of a channel next expires
+    fn cycles_to_step(&self) -> u32 {
+        self.square1
+            .cycles_to_step()
+            .min(self.square2.cycles_to_step())
+            .min(self.wave.cycles_to_step())
+            .min(self.noise.cycles_to_step())
+    }
+
+    /// Advance the APU by `cycles` T-cycles
+    /// The output only changes when the timer of a channel expires, so the cycles up to the next
+    /// expiry are output at once
+    pub fn run(&mut self, mut cycles: u32) {
+        while cycles > 0 {
+            let step = if self.power {
+                self.cycles_to_step().min(cycles)
+            } else {
+                cycles
+            };
+            if step > 1 {
+                self.output(step - 1);
+            }
+            if self.power {
+                self.square1.run(step);
+                self.square2.run(step);
+                self.wave.run(step);
+                self.noise.run(step);
+            }
+            self.output(1);
+            cycles -= step;
+        }
+        let max = self.left.sample_rate() as usize * MAX_BUFFERED_SECONDS;
+        if self.available() > max * 2 {
+            self.left.skip(self.available() - max);
+            self.right.skip(self.right.available() - max);
+        }
+    }
+
+    /// Output the current levels of the channels for `clocks` T-cycles
+    fn output(&mut self, clocks: u32) {
+        let channels = self.channel_outputs();
+        let (left, right) = self.mix(channels);
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(e) = recorder.record((left, right), channels, clocks) {
+                self.recorder = None;
+                self.recording_error = Some(e);
+            }
+        }
+        self.left.set_level(left);
+        self.right.set_level(right);
+        self.left.clock(clocks);
+        self.right.clock(clocks);
+    }
+
+    /// Run the current frame sequencer step, on the falling edge of DIV bit 4
+    pub fn step_frame_sequencer(&mut self) {
+        if !self.power {
+            return;
+        }
+        if self.frame_step & 1 == 0 {
+            self.square1.clock_length();
+            self.square2.clock_length();
+            self.wave.clock_length();
+            self.noise.clock_length();
+        }
+        if self.frame_step == 2 || self.frame_step == 6 {
+            self.square1.clock_sweep();
+        }
+        if self.frame_step == 7 {
+            self.square1.envelope.clock();
+            self.square2.envelope.clock();
+            self.noise.envelope.clock();
+        }
+        self.frame_step = (self.frame_step + 1) % 8;
+    }
+
+    /// Analog output of each channel, in the range -1.0..=1.0
+    /// A channel whose DAC is off outputs nothing
+    pub fn channel_outputs(&self) -> [f32; 4] {
+        let dac = |enabled: bool, output: u8| {
+            if enabled {
+                output as f32 / 7.5 - 1.0
+            } else {
+                0.0
+            }
+        };
+        [
+            dac(self.square1.envelope.dac_enabled(), self.square1.output()),
+            dac(self.square2.envelope.dac_enabled(), self.square2.output()),
+            dac(self.wave.dac_enabled(), self.wave.output()),
+            dac(self.noise.envelope.dac_enabled(), self.noise.output()),
+        ]
+    }
+
+    /// Mix the channels into a left and right sample with NR51 panning and NR50 volume
+    fn mix(&self, channels: [f32; 4]) -> (f32, f32) {
+        let (mut left, mut right) = (0.0, 0.0);
+        for (i, output) in channels.iter().enumerate() {
+            if self.nr51 & (0x10 << i) != 0 {
+                left += output;
+            }
+            if self.nr51 & (0x01 << i) != 0 {
+                right += output;
+            }
+        }
+        let left_volume = ((self.nr50 >> 4) & 0b111) as f32 + 1.0;
+        let right_volume = (self.nr50 & 0b111) as f32 + 1.0;
+        (left * left_volume / 32.0, right * right_volume / 32.0)
+    }
+
+    /// Turning the APU off clears every register but wave RAM
+    fn power_off(&mut self) {
+        let ram = self.wave.ram;
+        self.square1 = Square::new(true);
+        self.square2 = Square::new(false);
+        self.wave = Wave::new();
+        self.wave.ram = ram;
+        self.noise = Noise::new();
+        self.nr50 = 0;
+        self.nr51 = 0;
+        self.power = false;
+    }
+
+    /// Read an audio register or wave RAM, unreadable bits read as 1
+    pub fn read(&self, addr: u16) -> u8 {
+        match addr {
+            CH1..=NR14 => self.square1.read(addr - CH1),
+            CH2..=0xFF19 => self.square2.read(addr - CH2),
+            CH3..=0xFF1E => self.wave.read(addr - CH3),
+            CH4..=0xFF23 => self.noise.read(addr - CH4),
+            NR50 => self.nr50,
+            NR51 => self.nr51,
+            NR52 => {
+                (self.power as u8) << 7
+                    | 0x70
+                    | (self.noise.enabled as u8) << 3
+                    | (self.wave.enabled as u8) << 2
+                    | (self.square2.enabled as u8) << 1
+                    | self.square1.enabled as u8
+            }
+            WAVE_RAM..=APU_END => self.wave.read_ram(addr - WAVE_RAM),
+            _ => 0xFF,
+        }
+    }
+
+    /// Write an audio register or wave RAM
+    /// While the APU is off only NR52 and wave RAM can be written, and on the DMG the length
+    /// counters through NRx1
+    pub fn write(&mut self, addr: u16, val: u8) {
+        if !self.power && addr < NR52 {
+            if !self.cgb {
+                match addr {
+                    NR11 => self.square1.length.load((val & 0x3F) as u16),
+                    NR21 => self.square2.length.load((val & 0x3F) as u16),
+                    NR31 => self.wave.length.load(val as u16),
+                    NR41 => self.noise.length.load((val & 0x3F) as u16),
+                    _ => {}
+                }
+            }
+            return;
+        }
+        let length_step = self.length_step();
+        match addr {
+            CH1..=NR14 => self.square1.write(addr - CH1, val, length_step),
+            CH2..=0xFF19 => self.square2.write(addr - CH2, val, length_step),
+            CH3..=0xFF1E => self.wave.write(addr - CH3, val, length_step),
+            CH4..=0xFF23 => self.noise.write(addr - CH4, val, length_step),
+            NR50 => self.nr50 = val,
+            NR51 => self.nr51 = val,
+            NR52 => {
+                if val & 0x80 == 0 {
+                    self.power_off();
+                } else if !self.power {
+                    self.power = true;
+                    self.frame_step = 0;
+                }
+            }
+            WAVE_RAM..=APU_END => self.wave.write_ram(addr - WAVE_RAM, val),
+            _ => {}
+        }
+    }
+}
+
+/// The resampled output and the recorder are not part of the state
+impl Savestate for Apu {
+    fn state(&mut self, state: &mut State) {
+        state.bool(&mut self.power);
+        self.square1.state(state);
+        self.square2.state(state);
+        self.wave.state(state);
+        self.noise.state(state);
+        state.u8(&mut self.nr50);
+        state.u8(&mut self.nr51);
+        state.u8(&mut self.frame_step);
+        state.bool(&mut self.cgb);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mem::Memory;
+    use super::super::rw::RWAccess;
+    use super::super::test_rom;
+    use super::super::timer::DIV;
+    use super::*;
+
+    const NR14: u16 = 0xFF14;
+    const NR42: u16 = 0xFF21;
+    const NR43: u16 = 0xFF22;
+    const NR44: u16 = 0xFF23;
+
+    fn powered() -> Apu {
+        let mut apu = Apu::new(DEFAULT_SAMPLE_RATE);
+        apu.write(NR52, 0x80);
+        apu
+    }
+
+    /// Whether channel `n` is on, from NR52
+    fn channel_on(apu: &Apu, n: u8) -> bool {
+        apu.read(NR52) & 1 << (n - 1) != 0
+    }
+
+    #[test]
+    fn length_counter_expires() {
+        let mut apu = powered();
+        apu.write(NR12, 0xF0);
+        // A length of 63 leaves a single step
+        apu.write(NR11, 0x3F);
+        apu.write(NR14, 0xC0);
+        assert!(channel_on(&apu, 1));
+        // Step 0 clocks the length counters
+        apu.step_frame_sequencer();
+        assert!(!channel_on(&apu, 1));
+
+        // Without the length enabled the channel keeps playing
+        apu.write(NR11, 0x3F);
+        apu.write(NR14, 0x80);
+        for _ in 0..8 {
+            apu.step_frame_sequencer();
+        }
+        assert!(channel_on(&apu, 1));
+    }
+
+    #[test]
+    fn sweep_overflow_disables_channel_1() {
+        let mut apu = powered();
+        apu.write(NR12, 0xF0);
+        // Pace 1, increase by period >> 1
+        apu.write(NR10, 0x11);
+        // Period 0x7FF overflows in the calculation done by the trigger
+        apu.write(NR13, 0xFF);
+        apu.write(NR14, 0x87);
+        assert!(!channel_on(&apu, 1));
+
+        // 1000 + 500 fits, the check of 1500 + 750 done right after overflows
+        apu.write(NR13, 0xE8);
+        apu.write(NR14, 0x83);
+        assert!(channel_on(&apu, 1));
+        // Steps 0 and 1 do not clock the sweep, step 2 does
+        apu.step_frame_sequencer();
+        apu.step_frame_sequencer();
+        assert!(channel_on(&apu, 1));
+        apu.step_frame_sequencer();
+        assert!(!channel_on(&apu, 1));
+    }
+
+    #[test]
+    fn envelope_steps_at_its_pace() {
+        let mut envelope = envelope::Envelope::new();
+        // Volume 5, decrease every 2 ticks
+        envelope.write(0x52);
+        envelope.trigger();
+        assert_eq!(envelope.volume, 5);
+        envelope.clock();
+        assert_eq!(envelope.volume, 5);
+        envelope.clock();
+        assert_eq!(envelope.volume, 4);
+        for _ in 0..20 {
+            envelope.clock();
+        }
+        assert_eq!(envelope.volume, 0);
+
+        // Increasing stops at 15
+        envelope.write(0xE9);
+        envelope.trigger();
+        envelope.clock();
+        assert_eq!(envelope.volume, 15);
+        envelope.clock();
+        assert_eq!(envelope.volume, 15);
+
+        // A pace of 0 never steps
+        envelope.write(0x80);
+        envelope.trigger();
+        for _ in 0..8 {
+            envelope.clock();
+        }
+        assert_eq!(envelope.volume, 8);
+    }
+
+    /// Output of the noise channel after each of `shifts` LFSR shifts with NR43 set to `nr43`
+    fn noise_outputs(nr43: u8, shifts: usize) -> Vec<u8> {
+        let mut apu = powered();
+        apu.write(NR42, 0xF0);
+        apu.write(NR43, nr43);
+        apu.write(NR44, 0x80);
+        (0..shifts)
+            .map(|_| {
+                // Divider 0 shifts every 8 T-cycles
+                for _ in 0..8 {
+                    apu.noise.tick();
+                }
+                apu.noise.output()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lfsr_7_bit_period() {
+        let outputs = noise_outputs(0x08, 127 * 3);
+        assert!(outputs.contains(&0) && outputs.contains(&15));
+        assert_eq!(outputs[..127 * 2], outputs[127..]);
+        // 127 is prime, so the sequence has no shorter period than 127 steps
+        assert_ne!(outputs[..126], outputs[1..127]);
+
+        // The 15-bit LFSR does not repeat after 127 shifts
+        let outputs = noise_outputs(0x00, 127 * 3);
+        assert_ne!(outputs[..127 * 2], outputs[127..]);
+    }
+
+    #[test]
+    fn power_off_clears_registers() {
+        let fresh = powered();
+        let mut apu = powered();
+        for addr in NR10..NR52 {
+            apu.write(addr, 0xFF);
+        }
+        apu.write(WAVE_RAM, 0x12);
+        apu.write(NR52, 0x00);
+        for addr in NR10..NR52 {
+            assert_eq!(apu.read(addr), fresh.read(addr), "{:#06X}", addr);
+        }
+        assert_eq!(apu.read(NR52), 0x70);
+        // Wave RAM is kept
+        assert_eq!(apu.read(WAVE_RAM), 0x12);
+
+        // Other writes are ignored while off
+        apu.write(NR50, 0x77);
+        apu.write(NR52, 0x80);
+        assert_eq!(apu.read(NR50), 0x00);
+    }
+
+    #[test]
+    fn length_writable_while_off_on_dmg_only() {
+        for model in [Model::DMG, Model::CGB] {
+            let mut apu = Apu::new(DEFAULT_SAMPLE_RATE);
+            apu.set_model(model);
+            apu.write(NR11, 0x3F);
+            apu.write(NR52, 0x80);
+            apu.write(NR12, 0xF0);
+            // A trigger only reloads an expired counter
+            apu.write(NR14, 0xC0);
+            apu.step_frame_sequencer();
+            assert_eq!(channel_on(&apu, 1), model.is_cgb(), "{:?}", model);
+        }
+    }
+
+    /// Frame sequencer step once the components are up to date, and bit 4 of DIV
+    fn sequencer(memory: &mut Memory) -> (u8, bool) {
+        memory.sync();
+        (memory.apu().frame_step, memory.read_8(DIV) & 0x10 != 0)
+    }
+
+    #[test]
+    fn frame_sequencer_steps_on_div_falling_edge() {
+        // jr @
+        let mut system = test_rom::system(&[0x18, 0xFE]);
+        let (mut step, mut bit) = sequencer(system.memory());
+        let mut edges = 0;
+        while edges < 4 {
+            system.run_cycles(4);
+            let (next_step, next_bit) = sequencer(system.memory());
+            let falling = bit && !next_bit;
+            assert_eq!(next_step, if falling { (step + 1) % 8 } else { step });
+            edges += falling as usize;
+            (step, bit) = (next_step, next_bit);
+        }
+
+        // Resetting DIV while bit 4 is set is a falling edge too
+        while !sequencer(system.memory()).1 {
+            system.run_cycles(4);
+        }
+        let (step, _) = sequencer(system.memory());
+        system.memory().write_8(DIV, 0);
+        assert_eq!(sequencer(system.memory()), ((step + 1) % 8, false));
+        // But not while it is clear
+        system.memory().write_8(DIV, 0);
+        assert_eq!(sequencer(system.memory()), ((step + 1) % 8, false));
+    }
+}
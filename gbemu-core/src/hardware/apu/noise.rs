@@ -0,0 +1,142 @@
+use super::super::state::{Savestate, State};
+use super::envelope::Envelope;
+use super::length::LengthCounter;
+
+/// Noise channel
+/// Outputs the inverted lowest bit of a linear feedback shift register
+/// NR41: --LL LLLL, initial length
+/// NR42: volume envelope
+/// NR43: SSSS WDDD, clock shift, LFSR width (0 = 15-bit, 1 = 7-bit) and clock divider
+/// NR44: TL-- ----, trigger and length enable
+pub struct Noise {
+    pub enabled: bool,
+    pub length: LengthCounter,
+    pub envelope: Envelope,
+    register: u8,
+    lfsr: u16,
+    timer: u32,
+}
+
+impl Noise {
+    /// Create a new Noise channel
+    pub fn new() -> Noise {
+        Noise {
+            enabled: false,
+            length: LengthCounter::new(64),
+            envelope: Envelope::new(),
+            register: 0,
+            lfsr: 0x7FFF,
+            timer: 0,
+        }
+    }
+
+    /// T-cycles between LFSR shifts
+    fn timer_period(&self) -> u32 {
+        let divider = match self.register & 0b111 {
+            0 => 8,
+            d => d as u32 * 16,
+        };
+        divider << (self.register >> 4)
+    }
+
+    /// T-cycles until the timer next expires
+    pub fn cycles_to_step(&self) -> u32 {
+        self.timer.max(1)
+    }
+
+    /// Advance the channel by `cycles` T-cycles, at most up to the next timer expiry
+    pub fn run(&mut self, cycles: u32) {
+        self.timer -= cycles - 1;
+        self.tick();
+    }
+
+    /// Advance the channel by one T-cycle
+    pub fn tick(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = self.timer_period();
+            let feedback = (self.lfsr ^ (self.lfsr >> 1)) & 1;
+            self.lfsr = (self.lfsr >> 1) | feedback << 14;
+            if self.register & 0b1000 != 0 {
+                // 7-bit mode also feeds back into bit 6
+                self.lfsr = (self.lfsr & !(1 << 6)) | feedback << 6;
+            }
+        }
+    }
+
+    /// Digital output of the channel, 0-15
+    pub fn output(&self) -> u8 {
+        if self.enabled && self.lfsr & 1 == 0 {
+            self.envelope.volume
+        } else {
+            0
+        }
+    }
+
+    /// Clock the length counter at 256 Hz
+    pub fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    /// Read register NR41-NR44, unreadable bits read as 1
+    pub fn read(&self, reg: u16) -> u8 {
+        match reg {
+            2 => self.envelope.read(),
+            3 => self.register,
+            4 => (self.length.enabled as u8) << 6 | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write register NR41-NR44
+    /// `length_step` is set if the next frame sequencer step clocks length counters
+    pub fn write(&mut self, reg: u16, val: u8, length_step: bool) {
+        match reg {
+            1 => self.length.load((val & 0x3F) as u16),
+            2 => {
+                self.envelope.write(val);
+                if !self.envelope.dac_enabled() {
+                    self.enabled = false;
+                }
+            }
+            3 => self.register = val,
+            4 => {
+                let trigger = val & 0x80 != 0;
+                if !self
+                    .length
+                    .write_control(val & 0x40 != 0, trigger, length_step)
+                {
+                    self.enabled = false;
+                }
+                if trigger {
+                    self.enabled = self.envelope.dac_enabled();
+                    self.envelope.trigger();
+                    self.lfsr = 0x7FFF;
+                    self.timer = self.timer_period();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Noise {
+    fn default() -> Noise {
+        Noise::new()
+    }
+}
+
+impl Savestate for Noise {
+    fn state(&mut self, state: &mut State) {
+        state.bool(&mut self.enabled);
+        self.length.state(state);
+        self.envelope.state(state);
+        state.u8(&mut self.register);
+        state.u16(&mut self.lfsr);
+        state.u32(&mut self.timer);
+    }
+}
@@ -0,0 +1,157 @@
+use super::resampler::BlipBuffer;
+use super::wav::WavWriter;
+use super::CLOCK_RATE;
+use std::io;
+use std::path::Path;
+
+/// Samples are written to the files in blocks of this many samples
+const BLOCK: usize = 4096;
+
+/// Audio recorder
+/// Resamples the APU output on its own, independently of what the frontend reads, so recording
+/// works in headless mode. Writes the stereo mix and optionally one mono file per channel, named
+/// after the mix with `_ch1` to `_ch4` appended.
+pub struct Recorder {
+    left: BlipBuffer,
+    right: BlipBuffer,
+    mix: WavWriter,
+    stems: Vec<(BlipBuffer, WavWriter)>,
+}
+
+impl Recorder {
+    /// Start recording to a WAV file at `sample_rate` Hz
+    pub fn create(path: &Path, sample_rate: u32, stems: bool, cgb: bool) -> io::Result<Recorder> {
+        let mut recorder = Recorder {
+            left: BlipBuffer::new(CLOCK_RATE, sample_rate, cgb),
+            right: BlipBuffer::new(CLOCK_RATE, sample_rate, cgb),
+            mix: WavWriter::create(path, 2, sample_rate)?,
+            stems: Vec::new(),
+        };
+        if stems {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            for channel in 1..=4 {
+                let path = path.with_file_name(format!("{}_ch{}.wav", stem, channel));
+                recorder.stems.push((
+                    BlipBuffer::new(CLOCK_RATE, sample_rate, cgb),
+                    WavWriter::create(&path, 1, sample_rate)?,
+                ));
+            }
+        }
+        Ok(recorder)
+    }
+
+    /// Record `clocks` T-cycles of output
+    pub fn record(
+        &mut self,
+        (left, right): (f32, f32),
+        channels: [f32; 4],
+        clocks: u32,
+    ) -> io::Result<()> {
+        self.left.set_level(left);
+        self.right.set_level(right);
+        self.left.clock(clocks);
+        self.right.clock(clocks);
+        for ((buffer, _), level) in self.stems.iter_mut().zip(channels) {
+            buffer.set_level(level);
+            buffer.clock(clocks);
+        }
+        if self.left.available() >= BLOCK {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write every sample ready to the files and update their headers, so a recording that is
+    /// never finished, e.g. stopped with Ctrl-C, is still readable
+    pub fn flush(&mut self) -> io::Result<()> {
+        let count = self.left.available();
+        let mut left = vec![0.0; count];
+        let mut right = vec![0.0; count];
+        self.left.read(&mut left);
+        self.right.read(&mut right);
+        let interleaved: Vec<f32> = left
+            .into_iter()
+            .zip(right)
+            .flat_map(|(l, r)| [l, r])
+            .collect();
+        self.mix.write(&interleaved)?;
+        self.mix.update_header()?;
+        for (buffer, wav) in self.stems.iter_mut() {
+            let mut samples = vec![0.0; buffer.available()];
+            buffer.read(&mut samples);
+            wav.write(&samples)?;
+            wav.update_header()?;
+        }
+        Ok(())
+    }
+
+    /// Write the remaining samples and finish the files
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.flush()?;
+        self.mix.finish()?;
+        for (_, wav) in self.stems.iter_mut() {
+            wav.finish()?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_rom;
+    use super::*;
+    use std::fs;
+
+    /// Little-endian u32 at `offset`
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Check the sizes in the header of a WAV file, returns its number of samples per channel
+    fn samples(path: &Path, channels: usize) -> usize {
+        let data = fs::read(path).unwrap();
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(u32_at(&data, 4) as usize, data.len() - 8);
+        assert_eq!(&data[36..40], b"data");
+        let size = u32_at(&data, 40) as usize;
+        assert_eq!(size, data.len() - 44);
+        size / (2 * channels)
+    }
+
+    #[test]
+    fn records_mix_and_stems() {
+        let dir = std::env::temp_dir().join(format!("gbemu-recorder-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("song.wav");
+        // jr @
+        let mut system = test_rom::system(&[0x18, 0xFE]);
+        let start = system.cycles();
+        system
+            .memory()
+            .apu()
+            .start_recording(&path, 44100, true)
+            .unwrap();
+        for _ in 0..10 {
+            system.run_frame();
+        }
+        system.memory().sync();
+        system.memory().apu().stop_recording().unwrap();
+
+        let expected = (system.cycles() - start) as usize * 44100 / CLOCK_RATE as usize;
+        let count = samples(&path, 2);
+        assert!(count.abs_diff(expected) <= 1, "{} {}", count, expected);
+        for channel in 1..=4 {
+            assert_eq!(
+                samples(&dir.join(format!("song_ch{}.wav", channel)), 1),
+                count
+            );
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,189 @@
+/// Sub-sample positions the band-limited step is computed for
+const PHASES: usize = 32;
+/// Output samples each band-limited step is spread over
+const TAPS: usize = 16;
+/// Cutoff of the band-limited step as a fraction of the output sample rate, below Nyquist (0.5)
+const CUTOFF: f64 = 0.45;
+
+/// High-pass filter removing the DC offset of the output, like the capacitor on the real hardware
+/// Documented at: https://gbdev.io/pandocs/Audio_details.html#obscure-behavior
+pub struct HighPass {
+    charge: f32,
+    capacitor: f32,
+}
+
+impl HighPass {
+    /// Create a new HighPass for output at `sample_rate` from a clock at `clock_rate`
+    /// The capacitor of the CGB discharges faster than the one of the DMG
+    pub fn new(cgb: bool, clock_rate: u32, sample_rate: u32) -> HighPass {
+        let factor: f64 = if cgb { 0.998943 } else { 0.999958 };
+        HighPass {
+            charge: factor.powf(clock_rate as f64 / sample_rate as f64) as f32,
+            capacitor: 0.0,
+        }
+    }
+
+    /// Filter one sample
+    pub fn process(&mut self, input: f32) -> f32 {
+        let output = input - self.capacitor;
+        self.capacitor = input - output * self.charge;
+        output
+    }
+}
+
+/// Band-limited synthesis buffer
+/// The APU output is a step function changing at most once per clock. Instead of sampling it,
+/// which aliases, every change is added to the output as a band-limited step at its exact
+/// position in time. The buffer holds the derivative of the output, reading integrates it.
+pub struct BlipBuffer {
+    clock_rate: u32,
+    sample_rate: u32,
+    /// Output samples per input clock
+    ratio: f64,
+    /// Time of the current clock in output samples, relative to the start of `deltas`
+    time: f64,
+    deltas: Vec<f32>,
+    /// Last level added with `set_level`
+    level: f32,
+    /// Sum of the deltas read so far
+    integrator: f32,
+    /// Band-limited impulse for every phase, each summing to 1
+    kernel: Vec<[f32; TAPS]>,
+    high_pass: HighPass,
+}
+
+impl BlipBuffer {
+    /// Create a new BlipBuffer turning a signal clocked at `clock_rate` into `sample_rate` samples
+    pub fn new(clock_rate: u32, sample_rate: u32, cgb: bool) -> BlipBuffer {
+        let kernel = (0..PHASES)
+            .map(|phase| {
+                let mut taps = [0.0; TAPS];
+                for (k, tap) in taps.iter_mut().enumerate() {
+                    // Windowed sinc centred in the taps, shifted by the phase
+                    let t = k as f64 - (TAPS / 2) as f64 + 1.0 - phase as f64 / PHASES as f64;
+                    let x = 2.0 * CUTOFF * t;
+                    let sinc = if x == 0.0 {
+                        1.0
+                    } else {
+                        (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+                    };
+                    let w = (t + TAPS as f64 / 2.0) / TAPS as f64;
+                    let blackman = 0.42 - 0.5 * (2.0 * std::f64::consts::PI * w).cos()
+                        + 0.08 * (4.0 * std::f64::consts::PI * w).cos();
+                    *tap = (sinc * blackman.max(0.0)) as f32;
+                }
+                let sum: f32 = taps.iter().sum();
+                taps.map(|tap| tap / sum)
+            })
+            .collect();
+        BlipBuffer {
+            clock_rate,
+            sample_rate,
+            ratio: sample_rate as f64 / clock_rate as f64,
+            time: 0.0,
+            deltas: vec![0.0; TAPS],
+            level: 0.0,
+            integrator: 0.0,
+            kernel,
+            high_pass: HighPass::new(cgb, clock_rate, sample_rate),
+        }
+    }
+
+    /// Output sample rate
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Scale the output rate by `adjust` to produce slightly more or fewer samples
+    pub fn set_rate_adjust(&mut self, adjust: f64) {
+        self.ratio = self.sample_rate as f64 / self.clock_rate as f64 * adjust;
+    }
+
+    /// Set the level of the signal at the current clock
+    pub fn set_level(&mut self, level: f32) {
+        let delta = level - self.level;
+        if delta == 0.0 {
+            return;
+        }
+        self.level = level;
+        let index = self.time as usize;
+        let phase = ((self.time - index as f64) * PHASES as f64) as usize;
+        if self.deltas.len() < index + TAPS {
+            self.deltas.resize(index + TAPS, 0.0);
+        }
+        for (k, tap) in self.kernel[phase].iter().enumerate() {
+            self.deltas[index + k] += delta * tap;
+        }
+    }
+
+    /// Advance by `clocks` clocks
+    pub fn clock(&mut self, clocks: u32) {
+        self.time += self.ratio * clocks as f64;
+        // Keep room for every sample up to the current time, even if the level did not change
+        let len = self.time as usize + TAPS;
+        if self.deltas.len() < len {
+            self.deltas.resize(len, 0.0);
+        }
+    }
+
+    /// Number of samples ready to be read
+    pub fn available(&self) -> usize {
+        self.time as usize
+    }
+
+    /// Read samples into `out` until it is full or no sample is ready
+    /// Returns the number of samples read
+    pub fn read<'a>(&mut self, out: impl IntoIterator<Item = &'a mut f32>) -> usize {
+        let mut count = 0;
+        for (sample, delta) in out.into_iter().zip(&self.deltas[..self.available()]) {
+            self.integrator += delta;
+            *sample = self.high_pass.process(self.integrator);
+            count += 1;
+        }
+        self.deltas.drain(..count);
+        self.time -= count as f64;
+        if self.deltas.len() < TAPS {
+            self.deltas.resize(TAPS, 0.0);
+        }
+        count
+    }
+
+    /// Drop the oldest `count` samples
+    pub fn skip(&mut self, count: usize) {
+        let count = self.available().min(count);
+        for delta in self.deltas.drain(..count) {
+            self.integrator += delta;
+            self.high_pass.process(self.integrator);
+        }
+        self.time -= count as f64;
+        if self.deltas.len() < TAPS {
+            self.deltas.resize(TAPS, 0.0);
+        }
+    }
+}
+
+/// Dynamic rate control
+/// Keeps the audio queue of a frontend near a target length when the emulator runs slightly
+/// faster or slower than the audio device, by adjusting the output rate by at most `max_delta`.
+/// The pitch change is too small to hear.
+/// Documented at: https://docs.libretro.com/development/cores/dynamic-rate-control/
+pub struct DynamicRateControl {
+    target: usize,
+    max_delta: f64,
+}
+
+impl DynamicRateControl {
+    /// Create a new DynamicRateControl aiming for `target` queued samples
+    pub fn new(target: usize) -> DynamicRateControl {
+        DynamicRateControl {
+            target,
+            max_delta: 0.005,
+        }
+    }
+
+    /// Rate adjustment for the current queue length, to be passed to `set_rate_adjust`
+    pub fn adjust(&self, queued: usize) -> f64 {
+        let error = (self.target as f64 - queued as f64) / self.target.max(1) as f64;
+        1.0 + self.max_delta * error.clamp(-1.0, 1.0)
+    }
+}
@@ -0,0 +1,252 @@
+use super::super::state::{Savestate, State};
+use super::envelope::Envelope;
+use super::length::LengthCounter;
+
+/// Duty cycle waveforms, one bit per step
+/// 00: 12.5%, 01: 25%, 10: 50%, 11: 75%
+const DUTY: [u8; 4] = [0b00000001, 0b10000001, 0b10000111, 0b01111110];
+
+/// Frequency sweep of channel 1
+/// NR10: -PPP DSSS
+/// BIT 0-2: Step, the period is shifted right by `step` and added or subtracted
+/// BIT 3: Direction (0 = increase, 1 = decrease)
+/// BIT 4-6: Pace, the sweep is clocked every `pace` 128 Hz ticks (0 = disabled)
+struct Sweep {
+    register: u8,
+    enabled: bool,
+    shadow: u16,
+    timer: u8,
+    /// Whether a calculation was done in decrease mode since the last trigger
+    negated: bool,
+}
+
+impl Sweep {
+    fn new() -> Sweep {
+        Sweep {
+            register: 0,
+            enabled: false,
+            shadow: 0,
+            timer: 0,
+            negated: false,
+        }
+    }
+
+    fn pace(&self) -> u8 {
+        (self.register >> 4) & 0b111
+    }
+
+    fn reload_timer(&mut self) {
+        // A pace of 0 is treated as 8 by the timer
+        self.timer = if self.pace() == 0 { 8 } else { self.pace() };
+    }
+
+    /// Compute the next period, returns None if it overflows
+    fn calculate(&mut self) -> Option<u16> {
+        let delta = self.shadow >> (self.register & 0b111);
+        let period = if self.register & 0b1000 != 0 {
+            self.negated = true;
+            self.shadow - delta
+        } else {
+            self.shadow + delta
+        };
+        if period > 2047 {
+            None
+        } else {
+            Some(period)
+        }
+    }
+}
+
+/// Square wave channel, channel 1 has a sweep and channel 2 does not
+/// NRx1: DDLL LLLL, duty and initial length
+/// NRx2: volume envelope
+/// NRx3: lower 8 bits of the period
+/// NRx4: TL-- -PPP, trigger, length enable and upper 3 bits of the period
+pub struct Square {
+    sweep: Option<Sweep>,
+    pub enabled: bool,
+    duty: u8,
+    duty_step: u8,
+    pub length: LengthCounter,
+    pub envelope: Envelope,
+    period: u16,
+    timer: u16,
+}
+
+impl Square {
+    /// Create a new Square channel, with a sweep unit if `sweep` is set
+    pub fn new(sweep: bool) -> Square {
+        Square {
+            sweep: if sweep { Some(Sweep::new()) } else { None },
+            enabled: false,
+            duty: 0,
+            duty_step: 0,
+            length: LengthCounter::new(64),
+            envelope: Envelope::new(),
+            period: 0,
+            timer: 0,
+        }
+    }
+
+    /// T-cycles until the timer next expires
+    pub fn cycles_to_step(&self) -> u32 {
+        self.timer.max(1) as u32
+    }
+
+    /// Advance the channel by `cycles` T-cycles, at most up to the next timer expiry
+    pub fn run(&mut self, cycles: u32) {
+        self.timer -= (cycles - 1) as u16;
+        self.tick();
+    }
+
+    /// Advance the channel by one T-cycle
+    pub fn tick(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = (2048 - self.period) * 4;
+            self.duty_step = (self.duty_step + 1) % 8;
+        }
+    }
+
+    /// Digital output of the channel, 0-15
+    pub fn output(&self) -> u8 {
+        if self.enabled && (DUTY[self.duty as usize] >> self.duty_step) & 1 != 0 {
+            self.envelope.volume
+        } else {
+            0
+        }
+    }
+
+    /// Clock the length counter at 256 Hz
+    pub fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    /// Clock the sweep at 128 Hz
+    pub fn clock_sweep(&mut self) {
+        let Some(sweep) = &mut self.sweep else {
+            return;
+        };
+        sweep.timer = sweep.timer.saturating_sub(1);
+        if sweep.timer != 0 {
+            return;
+        }
+        sweep.reload_timer();
+        if !sweep.enabled || sweep.pace() == 0 {
+            return;
+        }
+        match sweep.calculate() {
+            Some(period) if sweep.register & 0b111 != 0 => {
+                sweep.shadow = period;
+                self.period = period;
+                // The new period is checked for overflow again, but not used
+                if sweep.calculate().is_none() {
+                    self.enabled = false;
+                }
+            }
+            Some(_) => {}
+            None => self.enabled = false,
+        }
+    }
+
+    /// Restart the channel
+    fn trigger(&mut self) {
+        self.enabled = self.envelope.dac_enabled();
+        self.timer = (2048 - self.period) * 4;
+        self.envelope.trigger();
+        if let Some(sweep) = &mut self.sweep {
+            sweep.shadow = self.period;
+            sweep.negated = false;
+            sweep.reload_timer();
+            sweep.enabled = sweep.pace() != 0 || sweep.register & 0b111 != 0;
+            if sweep.register & 0b111 != 0 && sweep.calculate().is_none() {
+                self.enabled = false;
+            }
+        }
+    }
+
+    /// Read register NRx0-NRx4, unreadable bits read as 1
+    pub fn read(&self, reg: u16) -> u8 {
+        match reg {
+            0 => match &self.sweep {
+                Some(sweep) => sweep.register | 0x80,
+                None => 0xFF,
+            },
+            1 => self.duty << 6 | 0x3F,
+            2 => self.envelope.read(),
+            4 => (self.length.enabled as u8) << 6 | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write register NRx0-NRx4
+    /// `length_step` is set if the next frame sequencer step clocks length counters
+    pub fn write(&mut self, reg: u16, val: u8, length_step: bool) {
+        match reg {
+            0 => {
+                if let Some(sweep) = &mut self.sweep {
+                    // Leaving decrease mode after it was used for a calculation turns the channel off
+                    if sweep.negated && sweep.register & 0b1000 != 0 && val & 0b1000 == 0 {
+                        self.enabled = false;
+                    }
+                    sweep.register = val & 0x7F;
+                }
+            }
+            1 => {
+                self.duty = val >> 6;
+                self.length.load((val & 0x3F) as u16);
+            }
+            2 => {
+                self.envelope.write(val);
+                if !self.envelope.dac_enabled() {
+                    self.enabled = false;
+                }
+            }
+            3 => self.period = (self.period & 0x700) | val as u16,
+            4 => {
+                self.period = (self.period & 0xFF) | ((val & 0b111) as u16) << 8;
+                let trigger = val & 0x80 != 0;
+                if !self
+                    .length
+                    .write_control(val & 0x40 != 0, trigger, length_step)
+                {
+                    self.enabled = false;
+                }
+                if trigger {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Savestate for Sweep {
+    fn state(&mut self, state: &mut State) {
+        state.u8(&mut self.register);
+        state.bool(&mut self.enabled);
+        state.u16(&mut self.shadow);
+        state.u8(&mut self.timer);
+        state.bool(&mut self.negated);
+    }
+}
+
+/// Only channel 1 has a sweep, which is part of its state
+impl Savestate for Square {
+    fn state(&mut self, state: &mut State) {
+        if let Some(sweep) = &mut self.sweep {
+            sweep.state(state);
+        }
+        state.bool(&mut self.enabled);
+        state.u8(&mut self.duty);
+        state.u8(&mut self.duty_step);
+        self.length.state(state);
+        self.envelope.state(state);
+        state.u16(&mut self.period);
+        state.u16(&mut self.timer);
+    }
+}
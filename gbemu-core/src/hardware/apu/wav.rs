@@ -0,0 +1,84 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Writer for 16-bit PCM WAV files
+/// The sizes in the header are patched in when the writer is finished or dropped, and by
+/// `update_header`
+/// Documented at: http://soundfile.sapp.org/doc/WaveFormat/
+pub struct WavWriter {
+    file: BufWriter<File>,
+    channels: u16,
+    /// Bytes of sample data written
+    data_size: u32,
+    finished: bool,
+}
+
+impl WavWriter {
+    /// Create a WAV file with `channels` interleaved channels at `sample_rate` Hz
+    pub fn create(path: &Path, channels: u16, sample_rate: u32) -> io::Result<WavWriter> {
+        let mut file = BufWriter::new(File::create(path)?);
+        let block_align = channels * 2;
+        file.write_all(b"RIFF")?;
+        file.write_all(&0_u32.to_le_bytes())?;
+        file.write_all(b"WAVE")?;
+        file.write_all(b"fmt ")?;
+        file.write_all(&16_u32.to_le_bytes())?;
+        // Format 1 (PCM)
+        file.write_all(&1_u16.to_le_bytes())?;
+        file.write_all(&channels.to_le_bytes())?;
+        file.write_all(&sample_rate.to_le_bytes())?;
+        file.write_all(&(sample_rate * block_align as u32).to_le_bytes())?;
+        file.write_all(&block_align.to_le_bytes())?;
+        file.write_all(&16_u16.to_le_bytes())?;
+        file.write_all(b"data")?;
+        file.write_all(&0_u32.to_le_bytes())?;
+        Ok(WavWriter {
+            file,
+            channels,
+            data_size: 0,
+            finished: false,
+        })
+    }
+
+    /// Number of interleaved channels
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    /// Write interleaved samples in the range -1.0..=1.0
+    pub fn write(&mut self, samples: &[f32]) -> io::Result<()> {
+        for sample in samples {
+            let sample = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            self.file.write_all(&sample.to_le_bytes())?;
+        }
+        self.data_size += samples.len() as u32 * 2;
+        Ok(())
+    }
+
+    /// Patch the sizes of the samples written so far into the header and flush the file, so it
+    /// stays valid up to here if the writer is never finished
+    pub fn update_header(&mut self) -> io::Result<()> {
+        self.file.seek(SeekFrom::Start(4))?;
+        self.file.write_all(&(36 + self.data_size).to_le_bytes())?;
+        self.file.seek(SeekFrom::Start(40))?;
+        self.file.write_all(&self.data_size.to_le_bytes())?;
+        self.file.seek(SeekFrom::End(0))?;
+        self.file.flush()
+    }
+
+    /// Patch the sizes into the header and flush the file
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        self.update_header()
+    }
+}
+
+impl Drop for WavWriter {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
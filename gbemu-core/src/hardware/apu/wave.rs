@@ -0,0 +1,208 @@
+use super::super::state::{Savestate, State};
+use super::length::LengthCounter;
+
+/// Wave channel
+/// Plays the 32 4-bit samples of wave RAM [0xFF30..0xFF3F], upper nibble first
+/// NR30: D--- ----, DAC enable
+/// NR31: initial length
+/// NR32: -VV- ----, output level (00: mute, 01: 100%, 10: 50%, 11: 25%)
+/// NR33: lower 8 bits of the period
+/// NR34: TL-- -PPP, trigger, length enable and upper 3 bits of the period
+///
+/// On the DMG the CPU can only access wave RAM while the channel plays in the T-cycle the channel
+/// reads it, and retriggering the channel just as it reads wave RAM corrupts its first bytes.
+pub struct Wave {
+    /// Whether the DMG wave RAM quirks apply
+    pub dmg: bool,
+    pub enabled: bool,
+    dac_enabled: bool,
+    pub length: LengthCounter,
+    level: u8,
+    period: u16,
+    timer: u16,
+    /// Index of the current sample in wave RAM, 0-31
+    position: u8,
+    /// The sample last read from wave RAM
+    sample: u8,
+    /// Set in the T-cycle the channel reads wave RAM
+    reading: bool,
+    pub ram: [u8; 16],
+}
+
+impl Wave {
+    /// Create a new Wave channel
+    pub fn new() -> Wave {
+        Wave {
+            dmg: false,
+            enabled: false,
+            dac_enabled: false,
+            length: LengthCounter::new(256),
+            level: 0,
+            period: 0,
+            timer: 0,
+            position: 0,
+            sample: 0,
+            reading: false,
+            ram: [0; 16],
+        }
+    }
+
+    /// Whether the channel DAC is on
+    pub fn dac_enabled(&self) -> bool {
+        self.dac_enabled
+    }
+
+    /// T-cycles until the timer next expires
+    pub fn cycles_to_step(&self) -> u32 {
+        self.timer.max(1) as u32
+    }
+
+    /// Advance the channel by `cycles` T-cycles, at most up to the next timer expiry
+    pub fn run(&mut self, cycles: u32) {
+        self.timer -= (cycles - 1) as u16;
+        self.tick();
+    }
+
+    /// Advance the channel by one T-cycle
+    pub fn tick(&mut self) {
+        self.reading = false;
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = (2048 - self.period) * 2;
+            if self.enabled {
+                self.position = (self.position + 1) % 32;
+                self.reading = true;
+                let byte = self.ram[self.position as usize / 2];
+                self.sample = if self.position & 1 == 0 {
+                    byte >> 4
+                } else {
+                    byte & 0x0F
+                };
+            }
+        }
+    }
+
+    /// Digital output of the channel, 0-15
+    pub fn output(&self) -> u8 {
+        if !self.enabled || self.level == 0 {
+            return 0;
+        }
+        self.sample >> (self.level - 1)
+    }
+
+    /// Clock the length counter at 256 Hz
+    pub fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    /// Read wave RAM
+    /// While the channel plays, the CPU can only access the byte the channel is reading
+    pub fn read_ram(&self, index: u16) -> u8 {
+        if self.enabled {
+            if self.dmg && !self.reading {
+                return 0xFF;
+            }
+            self.ram[self.position as usize / 2]
+        } else {
+            self.ram[index as usize]
+        }
+    }
+
+    /// Write wave RAM
+    /// While the channel plays, the CPU can only access the byte the channel is reading
+    pub fn write_ram(&mut self, index: u16, val: u8) {
+        if self.enabled {
+            if self.dmg && !self.reading {
+                return;
+            }
+            self.ram[self.position as usize / 2] = val;
+        } else {
+            self.ram[index as usize] = val;
+        }
+    }
+
+    /// DMG retrigger corruption, the byte about to be read replaces the first byte of wave RAM,
+    /// or its aligned block of 4 bytes replaces the first 4 bytes
+    fn corrupt_ram(&mut self) {
+        let index = ((self.position as usize + 1) % 32) / 2;
+        if index < 4 {
+            self.ram[0] = self.ram[index];
+        } else {
+            let block = index & !3;
+            self.ram.copy_within(block..block + 4, 0);
+        }
+    }
+
+    /// Read register NR30-NR34, unreadable bits read as 1
+    pub fn read(&self, reg: u16) -> u8 {
+        match reg {
+            0 => (self.dac_enabled as u8) << 7 | 0x7F,
+            2 => self.level << 5 | 0x9F,
+            4 => (self.length.enabled as u8) << 6 | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write register NR30-NR34
+    /// `length_step` is set if the next frame sequencer step clocks length counters
+    pub fn write(&mut self, reg: u16, val: u8, length_step: bool) {
+        match reg {
+            0 => {
+                self.dac_enabled = val & 0x80 != 0;
+                if !self.dac_enabled {
+                    self.enabled = false;
+                }
+            }
+            1 => self.length.load(val as u16),
+            2 => self.level = (val >> 5) & 0b11,
+            3 => self.period = (self.period & 0x700) | val as u16,
+            4 => {
+                self.period = (self.period & 0xFF) | ((val & 0b111) as u16) << 8;
+                let trigger = val & 0x80 != 0;
+                if !self
+                    .length
+                    .write_control(val & 0x40 != 0, trigger, length_step)
+                {
+                    self.enabled = false;
+                }
+                if trigger {
+                    if self.dmg && self.enabled && self.timer == 1 {
+                        self.corrupt_ram();
+                    }
+                    self.enabled = self.dac_enabled;
+                    self.position = 0;
+                    // The first sample is played after a short delay
+                    self.timer = (2048 - self.period) * 2 + 6;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Wave {
+    fn default() -> Wave {
+        Wave::new()
+    }
+}
+
+impl Savestate for Wave {
+    fn state(&mut self, state: &mut State) {
+        state.bool(&mut self.dmg);
+        state.bool(&mut self.enabled);
+        state.bool(&mut self.dac_enabled);
+        self.length.state(state);
+        state.u8(&mut self.level);
+        state.u16(&mut self.period);
+        state.u16(&mut self.timer);
+        state.u8(&mut self.position);
+        state.check(self.position < 32, "wave position out of range");
+        state.u8(&mut self.sample);
+        state.bool(&mut self.reading);
+        state.bytes(&mut self.ram);
+    }
+}
@@ -0,0 +1,327 @@
+use super::state::{Savestate, State};
+use super::{
+    apu,
+    interrupt::IF,
+    joypad,
+    mem::{Memory, ROMAccess},
+    model::Model,
+    ppu,
+    rw::RWAccess,
+};
+use std::{fs, io, path::Path};
+
+/// BOOT [0xFF50]: Writing a non-zero value unmaps the boot ROM until the next reset
+pub const BOOT: u16 = 0xFF50;
+
+/// Sizes of DMG/MGB/SGB and CGB/AGB boot ROMs
+const DMG_BOOT_SIZE: usize = 0x100;
+const CGB_BOOT_SIZE: usize = 0x900;
+
+/// Nintendo logo as stored in the cartridge header
+const LOGO: u16 = 0x104;
+const LOGO_SIZE: u16 = 0x30;
+/// The ® tile placed after the logo
+const TRADEMARK: [u8; 8] = [0x3C, 0x42, 0xB9, 0xA5, 0xB9, 0xA5, 0x42, 0x3C];
+
+/// Boot ROM
+/// Mapped over the cartridge at [0x0000..0x00FF] until BOOT is written, CGB boot ROMs are also
+/// mapped at [0x0200..0x08FF] and leave the cartridge header at [0x0100..0x01FF] visible.
+/// Documented at: https://gbdev.io/pandocs/Power_Up_Sequence.html
+#[derive(Default)]
+pub struct BootRom {
+    data: Vec<u8>,
+}
+
+impl BootRom {
+    /// Load a boot ROM from a file
+    pub fn load(path: &Path) -> io::Result<BootRom> {
+        BootRom::from_data(fs::read(path)?)
+    }
+
+    /// Create a boot ROM from its bytes
+    pub fn from_data(data: Vec<u8>) -> io::Result<BootRom> {
+        if data.len() != DMG_BOOT_SIZE && data.len() != CGB_BOOT_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("boot ROM size {:#X} is neither 0x100 nor 0x900", data.len()),
+            ));
+        }
+        Ok(BootRom { data })
+    }
+
+    /// Read a byte of the boot ROM, None where the cartridge shows through
+    pub fn read(&self, addr: u16) -> Option<u8> {
+        match addr {
+            0x0000..=0x00FF => Some(self.data[addr as usize]),
+            0x0200..=0x08FF if self.data.len() == CGB_BOOT_SIZE => Some(self.data[addr as usize]),
+            _ => None,
+        }
+    }
+}
+
+/// Registers A, F, B, C, D, E, H and L as left by the boot ROM of the model
+/// Documented at: https://gbdev.io/pandocs/Power_Up_Sequence.html#cpu-registers
+pub fn registers(memory: &mut Memory) -> [u8; 8] {
+    let metadata = memory.get_metadata();
+    // H and C are left set by the header checksum check unless the checksum is 0
+    let dmg_flags = if metadata.header_checksum() == 0 {
+        0x80
+    } else {
+        0xB0
+    };
+    match memory.model() {
+        Model::DMG0 => [0x01, 0x00, 0xFF, 0x13, 0x00, 0xC1, 0x84, 0x03],
+        Model::DMG => [0x01, dmg_flags, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+        Model::MGB => [0xFF, dmg_flags, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+        Model::SGB => [0x01, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+        Model::SGB2 => [0xFF, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+        Model::CGB0 | Model::CGB | Model::AGB => {
+            let mut registers = if memory.is_cgb() {
+                [0x11, 0x80, 0x00, 0x00, 0xFF, 0x56, 0x00, 0x0D]
+            } else {
+                // In compatibility mode B holds the title checksum used to pick the palette
+                let b = if metadata.is_nintendo() {
+                    metadata.title_checksum()
+                } else {
+                    0x00
+                };
+                let hl: u16 = if b == 0x43 || b == 0x58 {
+                    0x991A
+                } else {
+                    0x007C
+                };
+                [0x11, 0x80, b, 0x00, 0x00, 0x08, (hl >> 8) as u8, hl as u8]
+            };
+            if memory.model() == Model::AGB {
+                // The AGB boot ROM ends with an INC B, which also sets the flags
+                let b = registers[2];
+                registers[2] = b.wrapping_add(1);
+                registers[1] = ((registers[2] == 0) as u8) << 7 | ((b & 0x0F == 0x0F) as u8) << 5;
+            }
+            registers
+        }
+    }
+}
+
+/// Set up the I/O registers and VRAM as left by the boot ROM, for starting without one
+/// Documented at: https://gbdev.io/pandocs/Power_Up_Sequence.html#hardware-registers
+pub fn skip(memory: &mut Memory) {
+    let model = memory.model();
+    // The internal counter depends on how long the boot ROM ran
+    let counter = match model {
+        Model::DMG0 => 0x1830,
+        Model::DMG | Model::MGB | Model::SGB | Model::SGB2 => 0xABCC,
+        Model::CGB0 | Model::CGB | Model::AGB => 0x267C,
+    };
+    memory.timer().set_counter(counter);
+    memory.write_8(joypad::P1, 0x00);
+    memory.write_8(IF, 0x01);
+
+    memory.write_8(apu::NR52, 0x80);
+    memory.write_8(apu::NR50, 0x77);
+    memory.write_8(apu::NR51, 0xF3);
+    memory.write_8(apu::NR11, 0x80);
+    if !model.is_sgb() {
+        // The DMG and CGB boot ROMs play their sound on channel 1, which is still enabled but has
+        // faded out by the time the cartridge starts
+        memory.write_8(apu::NR12, 0x08);
+        memory.write_8(apu::NR13, 0xC1);
+        memory.write_8(apu::NR14, 0x87);
+    }
+    memory.write_8(apu::NR12, 0xF3);
+
+    load_logo(memory);
+    if memory.is_cgb() {
+        // Background palettes are initialized to white
+        memory.write_8(ppu::BCPS, 0x80);
+        for i in 0..64 {
+            memory.write_8(ppu::BCPD, if i & 1 == 0 { 0xFF } else { 0x7F });
+        }
+    }
+    memory.write_8(ppu::BGP, 0xFC);
+    memory.write_8(ppu::OBP0, 0xFF);
+    memory.write_8(ppu::OBP1, 0xFF);
+    memory.write_8(ppu::LCDC, 0x91);
+}
+
+/// Decompress the logo from the cartridge header into tiles 1-24 with the ® as tile 25, and lay
+/// them out in the background map below the centre of the screen
+/// Each bit of the logo is doubled horizontally and every row is repeated
+fn load_logo(memory: &mut Memory) {
+    let mut addr = 0x8010;
+    for i in 0..LOGO_SIZE {
+        let byte = memory.read_8(LOGO + i);
+        for nibble in [byte >> 4, byte & 0x0F] {
+            let mut row = 0u8;
+            for bit in 0..4 {
+                if nibble & (1 << bit) != 0 {
+                    row |= 0b11 << (bit * 2);
+                }
+            }
+            for _ in 0..2 {
+                memory.write_8(addr, row);
+                addr += 2;
+            }
+        }
+    }
+    for row in TRADEMARK {
+        memory.write_8(addr, row);
+        addr += 2;
+    }
+    for tile in 1..=12 {
+        memory.write_8(0x9903 + tile, tile as u8);
+        memory.write_8(0x9923 + tile, tile as u8 + 12);
+    }
+    memory.write_8(0x9910, 0x19);
+}
+
+impl Savestate for BootRom {
+    fn state(&mut self, state: &mut State) {
+        state.vec(&mut self.data);
+        state.check(
+            self.data.len() == DMG_BOOT_SIZE || self.data.len() == CGB_BOOT_SIZE,
+            "boot ROM size is invalid",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_rom::{cgb_rom, rom, set_header, titled_rom};
+    use super::*;
+
+    /// Memory with `boot_rom` mapped over `rom`
+    fn booting(boot_rom: Vec<u8>, rom: &[u8]) -> Memory {
+        let mut memory = Memory::new();
+        memory.set_boot_rom(BootRom::from_data(boot_rom).unwrap());
+        memory.load_rom_data(rom).unwrap();
+        memory
+    }
+
+    #[test]
+    fn only_dmg_and_cgb_sizes_are_accepted() {
+        for size in [DMG_BOOT_SIZE, CGB_BOOT_SIZE] {
+            assert!(BootRom::from_data(vec![0; size]).is_ok());
+        }
+        for size in [0, 0xFF, 0x101, 0x800, 0x901] {
+            let err = BootRom::from_data(vec![0; size]).err().unwrap();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn boot_write_unmaps_the_boot_rom() {
+        let mut memory = booting(vec![0xAA; DMG_BOOT_SIZE], &rom(&[]));
+        assert_eq!(memory.read_8(0x0000), 0xAA);
+        assert_eq!(memory.read_8(0x00FF), 0xAA);
+        assert_eq!(memory.read_8(0x0101), 0xC3);
+        // DMG boot ROMs only cover the first 256 bytes
+        assert_eq!(memory.read_8(0x0200), 0x00);
+        memory.write_8(BOOT, 0x00);
+        assert!(memory.is_boot_rom_mapped());
+        assert_eq!(memory.read_8(0x0000), 0xAA);
+        memory.write_8(BOOT, 0x01);
+        assert!(!memory.is_boot_rom_mapped());
+        assert_eq!(memory.read_8(0x0000), 0x00);
+    }
+
+    #[test]
+    fn cgb_boot_rom_shows_the_cartridge_header() {
+        let rom = cgb_rom(&[]);
+        let memory = booting(vec![0xBB; CGB_BOOT_SIZE], &rom);
+        assert_eq!(memory.read_8(0x0000), 0xBB);
+        assert_eq!(memory.read_8(0x00FF), 0xBB);
+        for addr in 0x0100..0x0200 {
+            assert_eq!(memory.read_8(addr), rom[addr as usize]);
+        }
+        assert_eq!(memory.read_8(0x0200), 0xBB);
+        assert_eq!(memory.read_8(0x08FF), 0xBB);
+        assert_eq!(memory.read_8(0x0900), 0x00);
+    }
+
+    /// Registers left by the boot ROM of `model` for `rom`
+    fn boot_registers(model: Model, rom: &[u8]) -> [u8; 8] {
+        let mut memory = Memory::new();
+        memory.set_model(model);
+        memory.load_rom_data(rom).unwrap();
+        registers(&mut memory)
+    }
+
+    #[test]
+    fn registers_per_model() {
+        let dmg = rom(&[]);
+        let cgb = cgb_rom(&[]);
+        // A header checksum of 0 leaves H and C cleared
+        let mut zero_checksum = rom(&[]);
+        let version = zero_checksum[0x14D].wrapping_add(zero_checksum[0x14C]);
+        set_header(&mut zero_checksum, 0x14C, version);
+        assert_eq!(zero_checksum[0x14D], 0x00);
+        // Nintendo cartridge with a title checksum of 0xFF, which INC B overflows on the AGB
+        let nintendo = titled_rom(b"UUU", 0x01, &[]);
+        let cases: [(Model, &[u8], [u8; 8]); 12] = [
+            (
+                Model::DMG0,
+                &dmg,
+                [0x01, 0x00, 0xFF, 0x13, 0x00, 0xC1, 0x84, 0x03],
+            ),
+            (
+                Model::DMG,
+                &dmg,
+                [0x01, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+            ),
+            (
+                Model::DMG,
+                &zero_checksum,
+                [0x01, 0x80, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+            ),
+            (
+                Model::MGB,
+                &dmg,
+                [0xFF, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+            ),
+            (
+                Model::SGB,
+                &dmg,
+                [0x01, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+            ),
+            (
+                Model::SGB2,
+                &dmg,
+                [0xFF, 0x00, 0x00, 0x14, 0x00, 0x00, 0xC0, 0x60],
+            ),
+            (
+                Model::CGB,
+                &cgb,
+                [0x11, 0x80, 0x00, 0x00, 0xFF, 0x56, 0x00, 0x0D],
+            ),
+            (
+                Model::CGB,
+                &dmg,
+                [0x11, 0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x7C],
+            ),
+            (
+                Model::CGB,
+                &nintendo,
+                [0x11, 0x80, 0xFF, 0x00, 0x00, 0x08, 0x00, 0x7C],
+            ),
+            (
+                Model::AGB,
+                &cgb,
+                [0x11, 0x00, 0x01, 0x00, 0xFF, 0x56, 0x00, 0x0D],
+            ),
+            (
+                Model::AGB,
+                &dmg,
+                [0x11, 0x00, 0x01, 0x00, 0x00, 0x08, 0x00, 0x7C],
+            ),
+            (
+                Model::AGB,
+                &nintendo,
+                [0x11, 0xA0, 0x00, 0x00, 0x00, 0x08, 0x00, 0x7C],
+            ),
+        ];
+        for (model, rom, expected) in cases {
+            assert_eq!(boot_registers(model, rom), expected, "{:?}", model);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use super::cache::CachedInstruction;
+use super::mem::{self, Memory};
+use super::rw::RWAccess;
+
+/// Bus
+/// Everything the CPU reaches through the address bus. The CPU is handed the bus for every step
+/// instead of keeping a reference to it, so the system that owns the bus can lend it out safely.
+pub trait Bus: RWAccess {
+    /// Advance all components on the bus by `cycles` T-cycles of the CPU
+    fn advance(&mut self, cycles: u32);
+    /// T-cycles until the next event of a component, None if nothing is scheduled
+    fn cycles_to_event(&self) -> Option<u32>;
+    /// Read 8-bit value, bringing the components up to date first when it is an I/O register
+    fn read(&mut self, addr: u16) -> u8;
+    /// Instruction decoded earlier at `addr`, if it is cached
+    fn cached_instruction(&self, addr: u16) -> Option<CachedInstruction>;
+    /// Cache the instruction the CPU fetched from `addr`
+    fn cache_instruction(&mut self, addr: u16, cached: CachedInstruction);
+    /// Returns and clears the T-cycles the CPU has to stall for
+    fn take_stall_cycles(&mut self) -> u32;
+    /// Switch speed if armed, as done by STOP
+    fn speed_switch(&mut self) -> bool;
+}
+
+impl Bus for Memory {
+    fn advance(&mut self, cycles: u32) {
+        Memory::advance(self, cycles)
+    }
+    fn cycles_to_event(&self) -> Option<u32> {
+        Memory::cycles_to_event(self)
+    }
+    fn read(&mut self, addr: u16) -> u8 {
+        if mem::is_io(addr) {
+            self.sync();
+        }
+        self.read_8(addr)
+    }
+    fn cached_instruction(&self, addr: u16) -> Option<CachedInstruction> {
+        Memory::cached_instruction(self, addr)
+    }
+    fn cache_instruction(&mut self, addr: u16, cached: CachedInstruction) {
+        Memory::cache_instruction(self, addr, cached)
+    }
+    fn take_stall_cycles(&mut self) -> u32 {
+        Memory::take_stall_cycles(self)
+    }
+    fn speed_switch(&mut self) -> bool {
+        Memory::speed_switch(self)
+    }
+}
@@ -0,0 +1,210 @@
+use super::cpu::instruction::Instruction;
+use super::cpu::opcodes::Opcode;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Memory covered by the instruction cache
+pub enum CacheMode {
+    Off,
+    /// Cartridge ROM [0x0000..0x7FFF]
+    Rom,
+    /// ROM, external RAM, every bank of work RAM and HRAM
+    RomAndRam,
+}
+
+impl CacheMode {
+    /// Parse a mode from its name: off, rom or ram
+    pub fn parse(name: &str) -> Option<CacheMode> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "off" => CacheMode::Off,
+            "rom" => CacheMode::Rom,
+            "ram" => CacheMode::RomAndRam,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Instruction decoded from memory
+pub struct CachedInstruction {
+    pub opcode: &'static Opcode,
+    pub instruction: Instruction,
+    /// Bytes fetched by the CPU, including the CB prefix but not the byte skipped after STOP
+    pub fetches: u8,
+}
+
+/// Slots of the regions at a fixed address, one per address and bank
+const ROM0: usize = 0;
+const EXTERNAL_RAM: usize = ROM0 + 0x4000;
+const WRAM: usize = EXTERNAL_RAM + 0x2000;
+const HRAM: usize = WRAM + 8 * 0x1000;
+const SLOTS: usize = HRAM + 0x7F;
+/// Slots of each bank of switchable ROM [0x4000..0x7FFF]
+const ROM_BANK_SLOTS: usize = 0x4000;
+
+/// Decoded-instruction cache
+/// Instructions are kept by the address and bank they start at, so executing them again skips
+/// reading and decoding their bytes. The CPU still spends an M-cycle on every byte it fetches.
+/// Every write to cached RAM invalidates the instructions that may contain the written byte,
+/// which keeps self-modifying code in RAM correct. Writes to ROM only select banks, which is
+/// why switchable ROM is keyed by the bank mapped when the instruction was fetched rather than
+/// invalidated. An instruction is only cached when all of its bytes are in the same region and
+/// bank.
+/// Work RAM is keyed by the bank selected in SVBK.
+/// benches/cache.rs measures the speedup of each mode on code in ROM and in WRAM.
+pub struct InstructionCache {
+    mode: CacheMode,
+    /// Table 0 holds the regions at a fixed address, table 1 + n bank n of switchable ROM.
+    /// Tables of ROM banks are allocated when an instruction is first cached in them.
+    tables: Vec<Vec<Option<CachedInstruction>>>,
+}
+
+impl InstructionCache {
+    pub fn new(mode: CacheMode) -> InstructionCache {
+        let fixed = if mode == CacheMode::Off { 0 } else { SLOTS };
+        InstructionCache {
+            mode,
+            tables: vec![vec![None; fixed]],
+        }
+    }
+
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    /// Drop every cached instruction
+    pub fn clear(&mut self) {
+        self.tables.truncate(1);
+        self.tables[0].fill(None);
+    }
+
+    /// Table and slot of an address, None if it is not cached in this mode
+    fn slot(&self, addr: u16, rom_bank: usize, wram_bank: usize) -> Option<(usize, usize)> {
+        let addr = addr as usize;
+        let slot = match (self.mode, addr) {
+            (CacheMode::Off, _) => return None,
+            (_, 0x0000..=0x3FFF) => ROM0 + addr,
+            (_, 0x4000..=0x7FFF) => return Some((1 + rom_bank, addr - 0x4000)),
+            (CacheMode::Rom, _) => return None,
+            (_, 0xA000..=0xBFFF) => EXTERNAL_RAM + addr - 0xA000,
+            (_, 0xC000..=0xCFFF) => WRAM + addr - 0xC000,
+            (_, 0xD000..=0xDFFF) => WRAM + wram_bank * 0x1000 + addr - 0xD000,
+            (_, 0xFF80..=0xFFFE) => HRAM + addr - 0xFF80,
+            _ => return None,
+        };
+        Some((0, slot))
+    }
+
+    /// Instruction cached at `addr` with `rom_bank` mapped at 0x4000 and `wram_bank` at 0xD000
+    pub fn get(&self, addr: u16, rom_bank: usize, wram_bank: usize) -> Option<CachedInstruction> {
+        let (table, slot) = self.slot(addr, rom_bank, wram_bank)?;
+        self.tables.get(table)?.get(slot).copied().flatten()
+    }
+
+    /// Cache the instruction fetched from `addr`, unless its bytes leave the region
+    pub fn insert(
+        &mut self,
+        addr: u16,
+        rom_bank: usize,
+        wram_bank: usize,
+        cached: CachedInstruction,
+    ) {
+        let last = addr.wrapping_add(cached.fetches as u16 - 1);
+        // Slots of bank 0 and bank 1 of work RAM are contiguous, but the bank after 0xCFFF can
+        // be switched
+        if addr <= 0xCFFF && last >= 0xD000 {
+            return;
+        }
+        let (Some((table, first)), Some((last_table, end))) = (
+            self.slot(addr, rom_bank, wram_bank),
+            self.slot(last, rom_bank, wram_bank),
+        ) else {
+            return;
+        };
+        if table != last_table || end != first + cached.fetches as usize - 1 {
+            return;
+        }
+        if self.tables.len() <= table {
+            self.tables.resize_with(table + 1, Vec::new);
+        }
+        if self.tables[table].is_empty() {
+            self.tables[table] = vec![None; ROM_BANK_SLOTS];
+        }
+        self.tables[table][first] = Some(cached);
+    }
+
+    /// Invalidate the instructions containing the byte written at `addr`, which start up to 2
+    /// bytes before it
+    /// Writes to ROM do not change its contents, so nothing is invalidated by them
+    pub fn invalidate(&mut self, addr: u16, wram_bank: usize) {
+        if addr <= 0x7FFF {
+            return;
+        }
+        if let Some((table, slot)) = self.slot(addr, 0, wram_bank) {
+            for entry in &mut self.tables[table][slot.saturating_sub(2)..=slot] {
+                *entry = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cpu::opcodes::unprefixed_opcode_get;
+    use super::super::rw::RWAccess;
+    use super::super::test_rom;
+    use super::*;
+
+    fn nop() -> CachedInstruction {
+        CachedInstruction {
+            opcode: unprefixed_opcode_get(0x00),
+            instruction: Instruction::Nop,
+            fetches: 1,
+        }
+    }
+
+    #[test]
+    fn switchable_rom_is_keyed_by_bank() {
+        let mut cache = InstructionCache::new(CacheMode::Rom);
+        cache.insert(0x4000, 1, 1, nop());
+        assert!(cache.get(0x4000, 1, 1).is_some());
+        assert!(cache.get(0x4000, 2, 1).is_none());
+        // Bank 0 is fixed whatever bank is switched in
+        cache.insert(0x0100, 1, 1, nop());
+        assert!(cache.get(0x0100, 2, 1).is_some());
+        // A write to ROM selects a bank rather than changing the cached bytes
+        cache.invalidate(0x4000, 1);
+        assert!(cache.get(0x4000, 1, 1).is_some());
+    }
+
+    /// Overwrite the opcode of a routine in WRAM and HRAM after it ran and run it again
+    #[test]
+    fn self_modifying_code() {
+        for mode in [CacheMode::Off, CacheMode::Rom, CacheMode::RomAndRam] {
+            let mut system = test_rom::system(&[
+                0x3E, 0x3C, // ld a, $3C (inc a)
+                0xEA, 0x00, 0xC0, // ld [$C000], a
+                0xE0, 0x80, // ldh [$FF80], a
+                0x3E, 0xC9, // ld a, $C9 (ret)
+                0xEA, 0x01, 0xC0, // ld [$C001], a
+                0xE0, 0x81, // ldh [$FF81], a
+                0xAF, // xor a
+                0xCD, 0x00, 0xC0, // call $C000
+                0xCD, 0x80, 0xFF, // call $FF80
+                0xEA, 0x00, 0xD0, // ld [$D000], a
+                0x47, // ld b, a
+                0x3E, 0x3D, // ld a, $3D (dec a)
+                0xEA, 0x00, 0xC0, // ld [$C000], a
+                0xE0, 0x80, // ldh [$FF80], a
+                0x78, // ld a, b
+                0xCD, 0x00, 0xC0, // call $C000
+                0xCD, 0x80, 0xFF, // call $FF80
+                0xEA, 0x01, 0xD0, // ld [$D001], a
+                0x18, 0xFE, // jr @
+            ]);
+            system.memory().set_instruction_cache(mode);
+            system.run_cycles(1000);
+            assert_eq!(system.memory().read_8(0xD000), 2, "{:?}", mode);
+            assert_eq!(system.memory().read_8(0xD001), 0, "{:?}", mode);
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use super::joypad::Button;
+use super::mem::ROMMetadata;
+use super::ppu::palette::rgb555_to_rgb888;
+use super::state::{Savestate, State};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// Colourization palette the CGB boot ROM gives a DMG cartridge in compatibility mode
+/// Colours are RGB555 for the BG, OBP0 and OBP1, from DMG shade 0 (white) to 3 (black), as they
+/// are written to the CGB palette RAM
+/// Documented at: https://gbdev.io/pandocs/Power_Up_Sequence.html#compatibility-palettes
+pub struct CompatPalette {
+    pub bg: [u16; 4],
+    pub obj0: [u16; 4],
+    pub obj1: [u16; 4],
+}
+
+/// Colours of the boot ROM palettes as RGB555, four per palette from shade 0 to 3
+const COLOURS: [u16; 120] = [
+    0x7FFF, 0x32BF, 0x00D0, 0x0000, // 0
+    0x639F, 0x4279, 0x15B0, 0x04CB, // 1
+    0x7FFF, 0x6E31, 0x454A, 0x0000, // 2
+    0x7FFF, 0x1BEF, 0x0200, 0x0000, // 3
+    0x7FFF, 0x421F, 0x1CF2, 0x0000, // 4
+    0x7FFF, 0x5294, 0x294A, 0x0000, // 5
+    0x7FFF, 0x03FF, 0x012F, 0x0000, // 6
+    0x7FFF, 0x03EF, 0x01D6, 0x0000, // 7
+    0x7FFF, 0x42B5, 0x3DC8, 0x0000, // 8
+    0x7E74, 0x03FF, 0x0180, 0x0000, // 9
+    0x67FF, 0x77AC, 0x1A13, 0x2D6B, // 10
+    0x7ED6, 0x4BFF, 0x2175, 0x0000, // 11
+    0x53FF, 0x4A5F, 0x7E52, 0x0000, // 12
+    0x4FFF, 0x7ED2, 0x3A4C, 0x1CE0, // 13
+    0x03ED, 0x7FFF, 0x255F, 0x0000, // 14
+    0x036A, 0x021F, 0x03FF, 0x7FFF, // 15
+    0x7FFF, 0x01DF, 0x0112, 0x0000, // 16
+    0x231F, 0x035F, 0x00F2, 0x0009, // 17
+    0x7FFF, 0x03EA, 0x011F, 0x0000, // 18
+    0x299F, 0x001A, 0x000C, 0x0000, // 19
+    0x7FFF, 0x027F, 0x001F, 0x0000, // 20
+    0x7FFF, 0x03E0, 0x0206, 0x0120, // 21
+    0x7FFF, 0x7EEB, 0x001F, 0x7C00, // 22
+    0x7FFF, 0x3FFF, 0x7E00, 0x001F, // 23
+    0x7FFF, 0x03FF, 0x001F, 0x0000, // 24
+    0x03FF, 0x001F, 0x000C, 0x0000, // 25
+    0x7FFF, 0x033F, 0x0193, 0x0000, // 26
+    0x0000, 0x4200, 0x037F, 0x7FFF, // 27
+    0x7FFF, 0x7E8C, 0x7C00, 0x0000, // 28
+    0x7FFF, 0x1BEF, 0x6180, 0x0000, // 29
+];
+
+/// Palette combinations of the boot ROM as offsets into `COLOURS` of the OBJ0, OBJ1 and BG
+/// palettes, a few of them start in the middle of a palette
+const COMBINATIONS: [(u8, u8, u8); 51] = [
+    (16, 16, 116),   // 0, Right + A, also the default
+    (72, 72, 72),    // 1, Right
+    (80, 80, 80),    // 2
+    (96, 96, 96),    // 3, Down + A
+    (36, 36, 36),    // 4
+    (0, 0, 0),       // 5, Up
+    (108, 108, 108), // 6, Right + B
+    (20, 20, 20),    // 7, Left + B
+    (48, 48, 48),    // 8, Down
+    (104, 104, 104), // 9
+    (64, 32, 32),    // 10
+    (16, 112, 112),  // 11
+    (16, 8, 8),      // 12
+    (12, 16, 16),    // 13
+    (16, 116, 116),  // 14
+    (112, 16, 112),  // 15
+    (8, 68, 8),      // 16
+    (64, 64, 32),    // 17
+    (16, 16, 28),    // 18
+    (16, 16, 72),    // 19
+    (16, 16, 80),    // 20
+    (76, 76, 36),    // 21
+    (15, 15, 44),    // 22
+    (68, 68, 8),     // 23
+    (16, 16, 8),     // 24
+    (16, 16, 12),    // 25
+    (112, 112, 0),   // 26
+    (12, 12, 0),     // 27
+    (0, 0, 4),       // 28, Up + B
+    (72, 88, 72),    // 29
+    (80, 88, 80),    // 30
+    (96, 88, 96),    // 31
+    (64, 88, 32),    // 32
+    (68, 16, 52),    // 33
+    (111, 0, 56),    // 34
+    (111, 16, 60),   // 35
+    (76, 88, 36),    // 36
+    (64, 112, 40),   // 37
+    (16, 92, 112),   // 38
+    (68, 88, 8),     // 39
+    (16, 0, 8),      // 40, Left + A
+    (16, 112, 12),   // 41
+    (112, 12, 0),    // 42
+    (12, 112, 16),   // 43, Up + A
+    (84, 112, 16),   // 44
+    (12, 112, 0),    // 45
+    (100, 12, 112),  // 46
+    (0, 112, 32),    // 47
+    (16, 12, 112),   // 48, Left
+    (112, 12, 24),   // 49, Down + B
+    (16, 112, 116),  // 50
+];
+
+/// Palette of four colours starting at `offset` in `COLOURS`
+const fn palette(offset: u8) -> [u16; 4] {
+    let offset = offset as usize;
+    [
+        COLOURS[offset],
+        COLOURS[offset + 1],
+        COLOURS[offset + 2],
+        COLOURS[offset + 3],
+    ]
+}
+
+/// Palette combination of the boot ROM by its index in `COMBINATIONS`
+const fn combination(index: u8) -> CompatPalette {
+    let (obj0, obj1, bg) = COMBINATIONS[index as usize];
+    CompatPalette {
+        bg: palette(bg),
+        obj0: palette(obj0),
+        obj1: palette(obj1),
+    }
+}
+
+/// Palettes selected by holding a direction, optionally with A or B, during the boot logo
+pub const UP: CompatPalette = combination(5);
+pub const UP_A: CompatPalette = combination(43);
+pub const UP_B: CompatPalette = combination(28);
+pub const LEFT: CompatPalette = combination(48);
+pub const LEFT_A: CompatPalette = combination(40);
+pub const LEFT_B: CompatPalette = combination(7);
+pub const DOWN: CompatPalette = combination(8);
+pub const DOWN_A: CompatPalette = combination(3);
+pub const DOWN_B: CompatPalette = combination(49);
+pub const RIGHT: CompatPalette = combination(1);
+pub const RIGHT_A: CompatPalette = combination(0);
+pub const RIGHT_B: CompatPalette = combination(6);
+
+/// Palette of cartridges the boot ROM does not recognize
+pub const DEFAULT: CompatPalette = RIGHT_A;
+
+/// Palette combinations of Nintendo cartridges recognized by their title checksum
+/// Entries are (checksum, fourth letter of the title, index in `COMBINATIONS`), the letter tells
+/// apart titles sharing a checksum and is 0 for checksums that are unique. As in the boot ROM,
+/// the first matching entry wins and a shared checksum without a matching letter is not
+/// recognized.
+pub static TITLE_PALETTES: &[(u8, u8, u8)] = &[
+    (0x88, 0, 4), // ALLEY WAY
+    (0x16, 0, 5),
+    (0x36, 0, 35),
+    (0xD1, 0, 34),
+    (0xDB, 0, 3), // TETRIS
+    (0xF2, 0, 31),
+    (0x3C, 0, 15), // DR.MARIO
+    (0x8C, 0, 10),
+    (0x92, 0, 5),
+    (0x3D, 0, 19),
+    (0x5C, 0, 36),
+    (0x58, 0, 7),
+    (0xC9, 0, 37),
+    (0x3E, 0, 30),
+    (0x70, 0, 44), // ZELDA
+    (0x1D, 0, 21),
+    (0x59, 0, 32),
+    (0x69, 0, 31),
+    (0x19, 0, 20),
+    (0x35, 0, 5),
+    (0xA8, 0, 33),
+    (0x14, 0, 13), // POKEMON RED
+    (0xAA, 0, 14), // POKEMON GREEN
+    (0x75, 0, 5),
+    (0x95, 0, 29),
+    (0x99, 0, 5),
+    (0x34, 0, 18),
+    (0x6F, 0, 9),
+    (0x15, 0, 3),
+    (0xFF, 0, 2),
+    (0x97, 0, 26),
+    (0x4B, 0, 25),
+    (0x90, 0, 25),
+    (0x17, 0, 41),
+    (0x10, 0, 42),
+    (0x39, 0, 26),
+    (0xF7, 0, 45),
+    (0xF6, 0, 42),
+    (0xA2, 0, 45),
+    (0x49, 0, 36),
+    (0x4E, 0, 38),
+    (0x43, 0, 26),
+    (0x68, 0, 42),
+    (0xE0, 0, 30),
+    (0x8B, 0, 41),
+    (0xF0, 0, 34),
+    (0xCE, 0, 34),
+    (0x0C, 0, 5),
+    (0x29, 0, 42),
+    (0xE8, 0, 6),
+    (0xB7, 0, 5),
+    (0x86, 0, 33),
+    (0x9A, 0, 25),
+    (0x52, 0, 42),
+    (0x01, 0, 42),
+    (0x9D, 0, 40),
+    (0x71, 0, 14),
+    (0x9C, 0, 16),
+    (0xBD, 0, 25),
+    (0x5D, 0, 42),
+    (0x6D, 0, 42),
+    (0x67, 0, 5),
+    (0x3F, 0, 0),
+    (0xE8, 0, 39),
+    (0xB3, b'B', 36),
+    (0x46, b'E', 22),
+    (0x28, b'F', 25),
+    (0xA5, b'A', 6),
+    (0xC6, b'A', 32),
+    (0xD3, b'R', 12),
+    (0x27, b'B', 36),
+    (0x61, b'E', 11),
+    (0x18, b'K', 39),
+    (0x66, b'E', 18),
+    (0x6A, b'K', 39),
+    (0xBF, b' ', 24),
+    (0x0D, b'R', 31),
+    (0xF4, b'-', 50),
+    (0xB3, b'U', 17),
+    (0x46, b'R', 46),
+    (0x28, b'A', 6),
+    (0xA5, b'R', 27),
+    (0xC6, b' ', 0),
+    (0xD3, b'I', 47),
+    (0x27, b'N', 41),
+    (0x61, b'A', 41),
+    (0x18, b'I', 0),
+    (0x66, b'L', 19),
+    (0x6A, b'I', 34),
+    (0xBF, b'C', 23),
+    (0x0D, b'E', 18),
+    (0xF4, b' ', 29),
+    (0xB3, b'R', 28),
+];
+
+impl CompatPalette {
+    /// Palette selected by holding `direction`, and optionally A or B, during the boot logo
+    pub fn manual(direction: Button, action: Option<Button>) -> Option<CompatPalette> {
+        match (direction, action) {
+            (Button::Up, None) => Some(UP),
+            (Button::Up, Some(Button::A)) => Some(UP_A),
+            (Button::Up, Some(Button::B)) => Some(UP_B),
+            (Button::Left, None) => Some(LEFT),
+            (Button::Left, Some(Button::A)) => Some(LEFT_A),
+            (Button::Left, Some(Button::B)) => Some(LEFT_B),
+            (Button::Down, None) => Some(DOWN),
+            (Button::Down, Some(Button::A)) => Some(DOWN_A),
+            (Button::Down, Some(Button::B)) => Some(DOWN_B),
+            (Button::Right, None) => Some(RIGHT),
+            (Button::Right, Some(Button::A)) => Some(RIGHT_A),
+            (Button::Right, Some(Button::B)) => Some(RIGHT_B),
+            _ => None,
+        }
+    }
+
+    /// Parse a button combination such as `up`, `left+a` or `right+b`
+    pub fn parse(combo: &str) -> Option<CompatPalette> {
+        let button = |name: &str| match name {
+            "up" => Some(Button::Up),
+            "down" => Some(Button::Down),
+            "left" => Some(Button::Left),
+            "right" => Some(Button::Right),
+            "a" => Some(Button::A),
+            "b" => Some(Button::B),
+            _ => None,
+        };
+        let combo = combo.to_lowercase();
+        let mut names = combo.split('+');
+        let direction = button(names.next()?)?;
+        let action = match names.next() {
+            Some(name) => Some(button(name)?),
+            None => None,
+        };
+        CompatPalette::manual(direction, action)
+    }
+
+    /// Palette the boot ROM picks for a cartridge
+    /// Only cartridges licensed by Nintendo are looked up by title checksum
+    pub fn select(metadata: &ROMMetadata) -> CompatPalette {
+        if !metadata.is_nintendo() {
+            return DEFAULT;
+        }
+        let checksum = metadata.title_checksum();
+        let fourth = metadata.title_bytes()[3];
+        TITLE_PALETTES
+            .iter()
+            .filter(|(sum, _, _)| *sum == checksum)
+            .find(|(_, letter, _)| *letter == 0 || *letter == fourth)
+            .map(|(_, _, index)| combination(*index))
+            .unwrap_or(DEFAULT)
+    }
+
+    /// Palette held by the joypad during boot, or the one picked for the cartridge
+    pub fn boot(metadata: &ROMMetadata, pressed: &dyn Fn(Button) -> bool) -> CompatPalette {
+        let direction = [Button::Up, Button::Left, Button::Down, Button::Right]
+            .into_iter()
+            .find(|button| pressed(*button));
+        let action = [Button::A, Button::B]
+            .into_iter()
+            .find(|button| pressed(*button));
+        direction
+            .and_then(|direction| CompatPalette::manual(direction, action))
+            .unwrap_or_else(|| CompatPalette::select(metadata))
+    }
+
+    /// Colours of the BG, OBP0 and OBP1 as RGB888 bytes, converted like the PPU converts CGB
+    /// colours with `correct` as its colour correction
+    pub fn rgb888(&self, correct: bool) -> [[[u8; 3]; 4]; 3] {
+        let convert = |colours: [u16; 4]| colours.map(|colour| rgb555_to_rgb888(colour, correct));
+        [convert(self.bg), convert(self.obj0), convert(self.obj1)]
+    }
+}
+
+impl Savestate for CompatPalette {
+    fn state(&mut self, state: &mut State) {
+        state.u16s(&mut self.bg);
+        state.u16s(&mut self.obj0);
+        state.u16s(&mut self.obj1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mem::{Memory, ROMAccess};
+    use super::super::test_rom;
+    use super::*;
+
+    /// Palette the boot ROM picks for a cartridge with `title` and an old licensee code
+    fn select(title: &[u8], licensee: u8) -> CompatPalette {
+        let mut memory = Memory::new();
+        memory
+            .load_rom_data(&test_rom::titled_rom(title, licensee, &[]))
+            .unwrap();
+        CompatPalette::select(&memory.get_metadata())
+    }
+
+    const WHITE_RED: [u16; 4] = [0x7FFF, 0x421F, 0x1CF2, 0x0000];
+    const WHITE_GREEN: [u16; 4] = [0x7FFF, 0x1BEF, 0x0200, 0x0000];
+
+    #[test]
+    fn tetris() {
+        let palette = select(b"TETRIS", 0x01);
+        assert_eq!(palette, DOWN_A);
+        assert_eq!(palette.bg, [0x7FFF, 0x03FF, 0x001F, 0x0000]);
+    }
+
+    #[test]
+    fn pokemon_red() {
+        let palette = select(b"POKEMON RED", 0x01);
+        assert_eq!(palette.bg, WHITE_RED);
+        assert_eq!(palette.obj0, WHITE_GREEN);
+        assert_eq!(palette.obj1, WHITE_RED);
+    }
+
+    #[test]
+    fn fourth_letter_tells_apart_shared_checksums() {
+        // All three titles sum to 0x61
+        let blue = select(b"POKEMON BLUE", 0x01);
+        assert_eq!(blue.bg, [0x7FFF, 0x7E8C, 0x7C00, 0x0000]);
+        assert_eq!(blue.obj0, WHITE_RED);
+        let vegas = select(b"VEGAS STAKES", 0x01);
+        assert_eq!(vegas.bg, WHITE_GREEN);
+        assert_eq!(select(b"POKXMON BLBE", 0x01), DEFAULT);
+    }
+
+    #[test]
+    fn only_nintendo_cartridges_are_recognized() {
+        assert_eq!(select(b"TETRIS", 0x33), DEFAULT);
+        assert_eq!(select(b"TETRIS", 0x08), DEFAULT);
+    }
+
+    #[test]
+    fn converted_like_cgb_colours() {
+        let colours = select(b"POKEMON RED", 0x01).rgb888(false);
+        assert_eq!(colours[0][0], [0xFF, 0xFF, 0xFF]);
+        assert_eq!(colours[0][1], [0xFF, 0x84, 0x84]);
+        assert_eq!(colours[0][2], [0x94, 0x39, 0x39]);
+        assert_eq!(colours[1][1], [0x7B, 0xFF, 0x31]);
+        let corrected = select(b"POKEMON RED", 0x01).rgb888(true);
+        assert_eq!(corrected[0][1], rgb555_to_rgb888(0x421F, true));
+        assert_ne!(corrected, colours);
+    }
+}
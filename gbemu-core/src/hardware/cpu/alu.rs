@@ -0,0 +1,202 @@
+use super::reg::Flag;
+
+/// Masks of the flags in the F register
+pub const Z: u8 = 1 << Flag::Zero as u8;
+pub const N: u8 = 1 << Flag::Subtract as u8;
+pub const H: u8 = 1 << Flag::HalfCarry as u8;
+pub const C: u8 = 1 << Flag::Carry as u8;
+
+// Arithmetic and logic of the CPU
+// Every operation returns its result and the new value of F
+
+/// Zero flag of a result
+fn zero(val: u8) -> u8 {
+    if val == 0 {
+        Z
+    } else {
+        0
+    }
+}
+
+/// Flag mask if `set`
+fn flag(set: bool, mask: u8) -> u8 {
+    if set {
+        mask
+    } else {
+        0
+    }
+}
+
+/// ADD and ADC
+pub fn add(a: u8, val: u8, carry: bool) -> (u8, u8) {
+    let carry = carry as u8;
+    let result = a.wrapping_add(val).wrapping_add(carry);
+    let half = (a & 0x0F) + (val & 0x0F) + carry > 0x0F;
+    let full = a as u16 + val as u16 + carry as u16 > 0xFF;
+    (result, zero(result) | flag(half, H) | flag(full, C))
+}
+
+/// SUB, SBC and CP
+pub fn sub(a: u8, val: u8, carry: bool) -> (u8, u8) {
+    let carry = carry as u8;
+    let result = a.wrapping_sub(val).wrapping_sub(carry);
+    let half = (a & 0x0F) < (val & 0x0F) + carry;
+    let full = (a as u16) < val as u16 + carry as u16;
+    (result, zero(result) | N | flag(half, H) | flag(full, C))
+}
+
+pub fn and(a: u8, val: u8) -> (u8, u8) {
+    let result = a & val;
+    (result, zero(result) | H)
+}
+
+pub fn xor(a: u8, val: u8) -> (u8, u8) {
+    let result = a ^ val;
+    (result, zero(result))
+}
+
+pub fn or(a: u8, val: u8) -> (u8, u8) {
+    let result = a | val;
+    (result, zero(result))
+}
+
+/// 8-bit INC, the carry flag is kept
+pub fn inc(val: u8, f: u8) -> (u8, u8) {
+    let result = val.wrapping_add(1);
+    (result, zero(result) | flag(val & 0x0F == 0x0F, H) | (f & C))
+}
+
+/// 8-bit DEC, the carry flag is kept
+pub fn dec(val: u8, f: u8) -> (u8, u8) {
+    let result = val.wrapping_sub(1);
+    (
+        result,
+        zero(result) | N | flag(val & 0x0F == 0, H) | (f & C),
+    )
+}
+
+/// ADD HL,rr, the zero flag is kept and the half carry is from bit 11
+pub fn add_16(hl: u16, val: u16, f: u8) -> (u16, u8) {
+    let result = hl.wrapping_add(val);
+    let half = (hl & 0x0FFF) + (val & 0x0FFF) > 0x0FFF;
+    let full = hl as u32 + val as u32 > 0xFFFF;
+    (result, (f & Z) | flag(half, H) | flag(full, C))
+}
+
+/// ADD SP,e8 and LD HL,SP+e8, the flags come from the addition of the lower byte
+pub fn add_sp(sp: u16, offset: u8) -> (u16, u8) {
+    let result = sp.wrapping_add(offset as i8 as u16);
+    let half = (sp & 0x0F) + (offset as u16 & 0x0F) > 0x0F;
+    let full = (sp & 0xFF) + offset as u16 > 0xFF;
+    (result, flag(half, H) | flag(full, C))
+}
+
+/// Decimal adjust A after a BCD addition or subtraction
+pub fn daa(a: u8, f: u8) -> (u8, u8) {
+    let mut adjust = 0;
+    let mut carry = f & C != 0;
+    if f & H != 0 || (f & N == 0 && a & 0x0F > 0x09) {
+        adjust |= 0x06;
+    }
+    if carry || (f & N == 0 && a > 0x99) {
+        adjust |= 0x60;
+        carry = true;
+    }
+    let result = if f & N != 0 {
+        a.wrapping_sub(adjust)
+    } else {
+        a.wrapping_add(adjust)
+    };
+    (result, zero(result) | (f & N) | flag(carry, C))
+}
+
+/// RLC, bit 7 goes to the carry and bit 0
+pub fn rlc(val: u8) -> (u8, u8) {
+    let result = val.rotate_left(1);
+    (result, zero(result) | flag(val & 0x80 != 0, C))
+}
+
+/// RRC, bit 0 goes to the carry and bit 7
+pub fn rrc(val: u8) -> (u8, u8) {
+    let result = val.rotate_right(1);
+    (result, zero(result) | flag(val & 0x01 != 0, C))
+}
+
+/// RL, rotate left through the carry
+pub fn rl(val: u8, f: u8) -> (u8, u8) {
+    let result = val << 1 | (f & C != 0) as u8;
+    (result, zero(result) | flag(val & 0x80 != 0, C))
+}
+
+/// RR, rotate right through the carry
+pub fn rr(val: u8, f: u8) -> (u8, u8) {
+    let result = val >> 1 | ((f & C != 0) as u8) << 7;
+    (result, zero(result) | flag(val & 0x01 != 0, C))
+}
+
+/// SLA, shift left into the carry
+pub fn sla(val: u8) -> (u8, u8) {
+    let result = val << 1;
+    (result, zero(result) | flag(val & 0x80 != 0, C))
+}
+
+/// SRA, shift right into the carry keeping bit 7
+pub fn sra(val: u8) -> (u8, u8) {
+    let result = val >> 1 | (val & 0x80);
+    (result, zero(result) | flag(val & 0x01 != 0, C))
+}
+
+/// SWAP the nibbles
+pub fn swap(val: u8) -> (u8, u8) {
+    let result = val.rotate_left(4);
+    (result, zero(result))
+}
+
+/// SRL, shift right into the carry
+pub fn srl(val: u8) -> (u8, u8) {
+    let result = val >> 1;
+    (result, zero(result) | flag(val & 0x01 != 0, C))
+}
+
+/// BIT, test a bit, the carry flag is kept
+pub fn bit(n: u8, val: u8, f: u8) -> u8 {
+    zero(val & (1 << n)) | H | (f & C)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daa_adjusts_additions_and_subtractions() {
+        let (a, f) = add(0x15, 0x27, false);
+        assert_eq!(daa(a, f), (0x42, 0));
+        let (a, f) = add(0x99, 0x01, false);
+        assert_eq!(daa(a, f), (0x00, Z | C));
+        let (a, f) = add(0x90, 0x90, false);
+        assert_eq!(daa(a, f), (0x80, C));
+        let (a, f) = sub(0x10, 0x01, false);
+        assert_eq!(daa(a, f), (0x09, N));
+        let (a, f) = sub(0x00, 0x01, false);
+        assert_eq!(daa(a, f), (0x99, N | C));
+    }
+
+    #[test]
+    fn adc_sbc_half_carry_includes_the_carry() {
+        assert_eq!(add(0x0F, 0x00, true), (0x10, H));
+        assert_eq!(add(0x0E, 0x01, false), (0x0F, 0));
+        assert_eq!(add(0xFF, 0x00, true), (0x00, Z | H | C));
+        assert_eq!(sub(0x10, 0x00, true), (0x0F, N | H));
+        assert_eq!(sub(0x10, 0x0F, true), (0x00, Z | N | H));
+        assert_eq!(sub(0x00, 0x00, true), (0xFF, N | H | C));
+    }
+
+    #[test]
+    fn add_sp_flags_come_from_the_low_byte() {
+        assert_eq!(add_sp(0xFFF8, 0x08), (0x0000, H | C));
+        assert_eq!(add_sp(0x00FF, 0x01), (0x0100, H | C));
+        assert_eq!(add_sp(0x0001, 0xFF), (0x0000, H | C));
+        assert_eq!(add_sp(0x1000, 0x80), (0x0F80, 0));
+        assert_eq!(add_sp(0x0000, 0xFF), (0xFFFF, 0));
+    }
+}
@@ -0,0 +1,342 @@
+use super::reg::{Reg16, Reg8};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Conditions of jumps, calls and returns
+pub enum Cond {
+    NZ,
+    Z,
+    NC,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// 8-bit immediate value
+pub struct Imm8(pub u8);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// 16-bit immediate value or address
+pub struct Imm16(pub u16);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Signed offset of relative jumps and of the stack pointer
+pub struct Rel8(pub i8);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Memory operands, by how their address is formed
+pub enum Ptr {
+    BC,
+    DE,
+    /// (HL+), increments HL after the access
+    HLI,
+    /// (HL-), decrements HL after the access
+    HLD,
+    /// (C), 0xFF00 + C
+    HighC,
+    /// (a8), 0xFF00 + a8
+    High(u8),
+    /// (a16)
+    Abs(u16),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Register or memory operand of 8-bit instructions
+/// F is never an operand, (HL) can be the operand of every 8-bit instruction and the other
+/// memory operands only of LD
+pub enum Target {
+    Reg(Reg8),
+    /// (HL)
+    HL,
+    Mem(Ptr),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Operations of the 8-bit arithmetic and logic instructions on A
+pub enum AluOp {
+    Add,
+    Adc,
+    Sub,
+    Sbc,
+    And,
+    Xor,
+    Or,
+    Cp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Rotates and shifts of the CB prefixed instructions
+pub enum ShiftOp {
+    Rlc,
+    Rrc,
+    Rl,
+    Rr,
+    Sla,
+    Sra,
+    Swap,
+    Srl,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Instructions of the CPU
+/// Decoding is generated by build.rs from resources/opcode/opcodes.json, every opcode maps to a
+/// variant with typed operands so executing an instruction is an exhaustive match.
+/// Displayed in the format of the opcode table, e.g. `LD A (HL+) ` or `JP NZ 0X150 `.
+pub enum Instruction {
+    Nop,
+    /// The byte after STOP is skipped, not fetched
+    Stop,
+    Halt,
+    Di,
+    Ei,
+    /// LD between registers and memory
+    Ld(Target, Target),
+    /// LD r8,n8 and LD (HL),n8
+    LdImm(Target, Imm8),
+    /// LD r16,n16
+    Ld16(Reg16, Imm16),
+    /// LD SP,HL
+    LdSpHl,
+    /// LD HL,SP+e8
+    LdHlSp(Rel8),
+    /// LD (a16),SP
+    LdAbsSp(Imm16),
+    Push(Reg16),
+    Pop(Reg16),
+    /// ADD, ADC, SUB, SBC, AND, XOR, OR and CP with A
+    Alu(AluOp, Target),
+    AluImm(AluOp, Imm8),
+    /// ADD HL,r16
+    AddHl(Reg16),
+    /// ADD SP,e8
+    AddSp(Rel8),
+    Inc(Target),
+    Dec(Target),
+    Inc16(Reg16),
+    Dec16(Reg16),
+    Daa,
+    Cpl,
+    Scf,
+    Ccf,
+    Rlca,
+    Rrca,
+    Rla,
+    Rra,
+    /// JP HL
+    JpHl,
+    Jp(Option<Cond>, Imm16),
+    Jr(Option<Cond>, Rel8),
+    Call(Option<Cond>, Imm16),
+    Ret(Option<Cond>),
+    Reti,
+    /// RST to the vector
+    Rst(u8),
+    Shift(ShiftOp, Target),
+    Bit(u8, Target),
+    Res(u8, Target),
+    Set(u8, Target),
+    /// Illegal opcode, locks up the CPU
+    Illegal(u8),
+}
+
+include!(concat!(env!("OUT_DIR"), "/instructions.rs"));
+
+impl fmt::Display for Reg8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for Reg16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for Cond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for Imm8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0X{:X}", self.0)
+    }
+}
+
+impl fmt::Display for Imm16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0X{:X}", self.0)
+    }
+}
+
+/// Shown as the byte it is encoded as
+impl fmt::Display for Rel8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0X{:X}", self.0 as u8)
+    }
+}
+
+impl fmt::Display for Ptr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Ptr::BC => write!(f, "(BC)"),
+            Ptr::DE => write!(f, "(DE)"),
+            Ptr::HLI => write!(f, "(HL+)"),
+            Ptr::HLD => write!(f, "(HL-)"),
+            Ptr::HighC => write!(f, "(C)"),
+            Ptr::High(addr) => write!(f, "(0X{:X})", addr),
+            Ptr::Abs(addr) => write!(f, "(0X{:X})", addr),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Target::Reg(reg) => write!(f, "{}", reg),
+            Target::HL => write!(f, "(HL)"),
+            Target::Mem(ptr) => write!(f, "{}", ptr),
+        }
+    }
+}
+
+impl fmt::Display for AluOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_uppercase())
+    }
+}
+
+impl fmt::Display for ShiftOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_uppercase())
+    }
+}
+
+/// Condition followed by a space, if any
+fn cond(cond: &Option<Cond>) -> String {
+    cond.map_or(String::new(), |cond| format!("{} ", cond))
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Nop => write!(f, "NOP "),
+            Instruction::Stop => write!(f, "STOP "),
+            Instruction::Halt => write!(f, "HALT "),
+            Instruction::Di => write!(f, "DI "),
+            Instruction::Ei => write!(f, "EI "),
+            // Accesses of (a8) are LDH, the others including (C) are LD
+            Instruction::Ld(dst @ Target::Mem(Ptr::High(_)), src)
+            | Instruction::Ld(dst, src @ Target::Mem(Ptr::High(_))) => {
+                write!(f, "LDH {} {} ", dst, src)
+            }
+            Instruction::Ld(dst, src) => write!(f, "LD {} {} ", dst, src),
+            Instruction::LdImm(dst, val) => write!(f, "LD {} {} ", dst, val),
+            Instruction::Ld16(reg, val) => write!(f, "LD {} {} ", reg, val),
+            Instruction::LdSpHl => write!(f, "LD SP HL "),
+            Instruction::LdHlSp(offset) => write!(f, "LD HL SP+ {} ", offset),
+            Instruction::LdAbsSp(addr) => write!(f, "LD (0X{:X}) SP ", addr.0),
+            Instruction::Push(reg) => write!(f, "PUSH {} ", reg),
+            Instruction::Pop(reg) => write!(f, "POP {} ", reg),
+            Instruction::Alu(op, src) => write!(f, "{} A {} ", op, src),
+            Instruction::AluImm(op, val) => write!(f, "{} A {} ", op, val),
+            Instruction::AddHl(reg) => write!(f, "ADD HL {} ", reg),
+            Instruction::AddSp(offset) => write!(f, "ADD SP {} ", offset),
+            Instruction::Inc(target) => write!(f, "INC {} ", target),
+            Instruction::Dec(target) => write!(f, "DEC {} ", target),
+            Instruction::Inc16(reg) => write!(f, "INC {} ", reg),
+            Instruction::Dec16(reg) => write!(f, "DEC {} ", reg),
+            Instruction::Daa => write!(f, "DAA "),
+            Instruction::Cpl => write!(f, "CPL "),
+            Instruction::Scf => write!(f, "SCF "),
+            Instruction::Ccf => write!(f, "CCF "),
+            Instruction::Rlca => write!(f, "RLCA "),
+            Instruction::Rrca => write!(f, "RRCA "),
+            Instruction::Rla => write!(f, "RLA "),
+            Instruction::Rra => write!(f, "RRA "),
+            Instruction::JpHl => write!(f, "JP HL "),
+            Instruction::Jp(c, addr) => write!(f, "JP {}{} ", cond(c), addr),
+            Instruction::Jr(c, offset) => write!(f, "JR {}{} ", cond(c), offset),
+            Instruction::Call(c, addr) => write!(f, "CALL {}{} ", cond(c), addr),
+            Instruction::Ret(c) => write!(f, "RET {}", cond(c)),
+            Instruction::Reti => write!(f, "RETI "),
+            Instruction::Rst(vector) => write!(f, "RST ${:02X} ", vector),
+            Instruction::Shift(op, target) => write!(f, "{} {} ", op, target),
+            Instruction::Bit(n, target) => write!(f, "BIT {} {} ", n, target),
+            Instruction::Res(n, target) => write!(f, "RES {} {} ", n, target),
+            Instruction::Set(n, target) => write!(f, "SET {} {} ", n, target),
+            Instruction::Illegal(opcode) => write!(f, "ILLEGAL_{:02X} ", opcode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::opcodes::{cb_prefixed_opcode_get, unprefixed_opcode_get};
+    use super::*;
+
+    /// Decodes `bytes` and returns the instruction with the number of bytes it took
+    fn decode(bytes: &[u8]) -> (Instruction, u8) {
+        let mut len = 1;
+        let instruction = Instruction::unprefixed(bytes[0], &mut || {
+            len += 1;
+            bytes.get(len as usize - 1).copied().unwrap_or(0)
+        });
+        (instruction, len)
+    }
+
+    #[test]
+    fn decoded_length_matches_table() {
+        for opcode in 0..=0xFF {
+            let (_, len) = decode(&[opcode]);
+            let expected = match opcode {
+                // STOP is followed by a byte the decoder does not fetch, the CPU skips it
+                0x10 => unprefixed_opcode_get(opcode).bytes - 1,
+                // The prefix fetches the CB opcode
+                0xCB => 2,
+                _ => unprefixed_opcode_get(opcode).bytes,
+            };
+            assert_eq!(len, expected, "opcode {:02X}", opcode);
+        }
+        for opcode in 0..=0xFF {
+            let (instruction, len) = decode(&[0xCB, opcode]);
+            assert_eq!(
+                len,
+                cb_prefixed_opcode_get(opcode).bytes,
+                "CB {:02X}",
+                opcode
+            );
+            assert_eq!(instruction, Instruction::cb_prefixed(opcode));
+            let mnemonic = cb_prefixed_opcode_get(opcode).mnemonic;
+            assert!(
+                instruction.to_string().starts_with(mnemonic),
+                "CB {:02X}",
+                opcode
+            );
+        }
+    }
+
+    #[test]
+    fn display_matches_previous_output() {
+        let cases: [(&[u8], &str); 14] = [
+            (&[0x00], "NOP "),
+            (&[0x2A], "LD A (HL+) "),
+            (&[0x32], "LD (HL-) A "),
+            (&[0x36, 0x12], "LD (HL) 0X12 "),
+            (&[0x01, 0x34, 0x12], "LD BC 0X1234 "),
+            (&[0x08, 0x00, 0xC0], "LD (0XC000) SP "),
+            (&[0xC2, 0x50, 0x01], "JP NZ 0X150 "),
+            (&[0x18, 0xFE], "JR 0XFE "),
+            (&[0xC9], "RET "),
+            (&[0xE0, 0x44], "LDH (0X44) A "),
+            (&[0xE2], "LD (C) A "),
+            (&[0xFF], "RST $38 "),
+            (&[0xCB, 0x7E], "BIT 7 (HL) "),
+            (&[0xD3], "ILLEGAL_D3 "),
+        ];
+        for (bytes, text) in cases {
+            assert_eq!(decode(bytes).0.to_string(), text);
+        }
+    }
+}
@@ -0,0 +1,677 @@
+pub mod alu;
+pub mod instruction;
+pub mod opcodes;
+pub mod reg;
+
+use self::instruction::{AluOp, Cond, Imm16, Imm8, Instruction, Ptr, Rel8, ShiftOp, Target};
+use self::opcodes::{cb_prefixed_opcode_get, unprefixed_opcode_get, Opcode};
+use self::reg::{Reg16, Reg8};
+use super::super::hardware::boot;
+use super::super::hardware::bus::Bus;
+use super::super::hardware::cache::CachedInstruction;
+use super::super::hardware::interrupt::{IE, IF};
+use super::super::hardware::mem::Memory;
+use super::super::hardware::state::{Savestate, State};
+
+/// Where an 8-bit operand is read from or written to, resolved once so (HL+) and (HL-) only
+/// change HL once
+#[derive(Clone, Copy)]
+enum Location {
+    /// 8-bit register
+    Reg8(Reg8),
+    /// Memory at an address, every access takes an M-cycle
+    Memory(u16),
+}
+
+pub struct CPU {
+    /// Registers
+    registers: reg::Registers,
+    /// Interrupt master enable
+    ime: bool,
+    /// Set by EI, interrupts are enabled once the next instruction is executed
+    ime_pending: bool,
+    /// Set by HALT until an interrupt is pending
+    halted: bool,
+    /// Set when HALT is executed with IME off and an interrupt pending, which makes the CPU
+    /// fetch the next byte twice
+    halt_bug: bool,
+    /// Address of the illegal opcode that locked up the CPU
+    locked: Option<u16>,
+    /// T-cycles elapsed in the current step
+    cycles: u32,
+}
+
+impl CPU {
+    /// Create a new CPU
+    /// With a boot ROM mapped all registers are initialized to 0 to run it, otherwise they are
+    /// set as the boot ROM leaves them and execution starts at the cartridge entry point 0x100
+    pub fn new(memory: &mut Memory) -> CPU {
+        let mut registers = reg::Registers::new();
+        if !memory.is_boot_rom_mapped() {
+            let values = boot::registers(memory);
+            let order = [
+                Reg8::A,
+                Reg8::F,
+                Reg8::B,
+                Reg8::C,
+                Reg8::D,
+                Reg8::E,
+                Reg8::H,
+                Reg8::L,
+            ];
+            for (reg, val) in order.into_iter().zip(values) {
+                registers.write_8(reg, val);
+            }
+            registers.write_16(Reg16::SP, 0xFFFE);
+            registers.write_16(Reg16::PC, 0x100);
+        }
+        CPU {
+            registers,
+            ime: false,
+            ime_pending: false,
+            halted: false,
+            halt_bug: false,
+            locked: None,
+            cycles: 0,
+        }
+    }
+
+    /// Whether an illegal opcode locked up the CPU
+    pub fn is_locked(&self) -> bool {
+        self.locked.is_some()
+    }
+
+    /// Address of the illegal opcode that locked up the CPU
+    pub fn locked_at(&self) -> Option<u16> {
+        self.locked
+    }
+
+    /// Whether the CPU is halted waiting for an interrupt
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Address of the next instruction
+    pub fn pc(&self) -> u16 {
+        self.registers.read_16(Reg16::PC)
+    }
+}
+
+/// Bus access
+/// Every access takes an M-cycle, the rest of the system is advanced by 4 T-cycles before the
+/// access is made
+impl CPU {
+    /// Spend an M-cycle without accessing the bus
+    fn idle(&mut self, bus: &mut impl Bus) {
+        bus.advance(4);
+        self.cycles += 4;
+    }
+    fn read(&mut self, bus: &mut impl Bus, addr: u16) -> u8 {
+        self.idle(bus);
+        bus.read(addr)
+    }
+    fn write(&mut self, bus: &mut impl Bus, addr: u16, val: u8) {
+        self.idle(bus);
+        bus.write_8(addr, val);
+    }
+    /// Read the byte at PC and advance PC
+    fn fetch(&mut self, bus: &mut impl Bus) -> u8 {
+        let pc = self.registers.read_16(Reg16::PC);
+        let val = self.read(bus, pc);
+        if self.halt_bug {
+            self.halt_bug = false;
+        } else {
+            self.registers.write_16(Reg16::PC, pc.wrapping_add(1));
+        }
+        val
+    }
+    /// Push onto the stack, after an internal M-cycle to decrement SP
+    fn push(&mut self, bus: &mut impl Bus, val: u16) {
+        self.idle(bus);
+        let sp = self.registers.read_16(Reg16::SP);
+        self.write(bus, sp.wrapping_sub(1), (val >> 8) as u8);
+        self.write(bus, sp.wrapping_sub(2), val as u8);
+        self.registers.write_16(Reg16::SP, sp.wrapping_sub(2));
+    }
+    fn pop(&mut self, bus: &mut impl Bus) -> u16 {
+        let sp = self.registers.read_16(Reg16::SP);
+        let lo = self.read(bus, sp);
+        let hi = self.read(bus, sp.wrapping_add(1));
+        self.registers.write_16(Reg16::SP, sp.wrapping_add(2));
+        u16::from_le_bytes([lo, hi])
+    }
+    /// Fetch and decode an instruction, including the CB prefix and the immediate operands
+    /// A cached instruction takes the same M-cycles to fetch, but its bytes are not read again.
+    /// The HALT bug fetches a byte twice, so instructions fetched with it are not cached.
+    fn pop_pc(&mut self, bus: &mut impl Bus) -> (&'static Opcode, Instruction) {
+        let pc = self.registers.read_16(Reg16::PC);
+        let halt_bug = self.halt_bug;
+        if let Some(cached) = bus.cached_instruction(pc).filter(|_| !halt_bug) {
+            for _ in 0..cached.fetches {
+                self.idle(bus);
+            }
+            self.registers
+                .write_16(Reg16::PC, pc.wrapping_add(cached.fetches as u16));
+            return (cached.opcode, cached.instruction);
+        }
+        let opcode = self.fetch(bus);
+        let (opcode, instruction) = if opcode == 0xCB {
+            let opcode = self.fetch(bus);
+            (
+                cb_prefixed_opcode_get(opcode),
+                Instruction::cb_prefixed(opcode),
+            )
+        } else {
+            let instruction = Instruction::unprefixed(opcode, &mut || self.fetch(bus));
+            (unprefixed_opcode_get(opcode), instruction)
+        };
+        if !halt_bug {
+            let fetches = self.registers.read_16(Reg16::PC).wrapping_sub(pc) as u8;
+            bus.cache_instruction(
+                pc,
+                CachedInstruction {
+                    opcode,
+                    instruction,
+                    fetches,
+                },
+            );
+        }
+        (opcode, instruction)
+    }
+}
+
+/// Registers and operands
+impl CPU {
+    fn flags(&self) -> u8 {
+        self.registers.read_8(Reg8::F)
+    }
+    fn set_flags(&mut self, f: u8) {
+        self.registers.write_8(Reg8::F, f);
+    }
+    /// Whether a condition of a jump, call or return holds, None always does
+    fn condition(&self, cond: Option<Cond>) -> bool {
+        match cond {
+            None => true,
+            Some(Cond::NZ) => !self.registers.zero(),
+            Some(Cond::Z) => self.registers.zero(),
+            Some(Cond::NC) => !self.registers.carry(),
+            Some(Cond::C) => self.registers.carry(),
+        }
+    }
+    /// Resolve a register or memory operand
+    /// (HL+) and (HL-) change HL as they are resolved
+    fn locate(&mut self, target: Target) -> Location {
+        let addr = match target {
+            Target::Reg(reg) => return Location::Reg8(reg),
+            Target::Mem(Ptr::BC) => self.registers.read_16(Reg16::BC),
+            Target::Mem(Ptr::DE) => self.registers.read_16(Reg16::DE),
+            Target::HL => self.registers.read_16(Reg16::HL),
+            Target::Mem(Ptr::HLI) => {
+                let hl = self.registers.read_16(Reg16::HL);
+                self.registers.write_16(Reg16::HL, hl.wrapping_add(1));
+                hl
+            }
+            Target::Mem(Ptr::HLD) => {
+                let hl = self.registers.read_16(Reg16::HL);
+                self.registers.write_16(Reg16::HL, hl.wrapping_sub(1));
+                hl
+            }
+            Target::Mem(Ptr::HighC) => 0xFF00 | self.registers.read_8(Reg8::C) as u16,
+            Target::Mem(Ptr::High(offset)) => 0xFF00 | offset as u16,
+            Target::Mem(Ptr::Abs(addr)) => addr,
+        };
+        Location::Memory(addr)
+    }
+    fn get_8(&mut self, bus: &mut impl Bus, location: Location) -> u8 {
+        match location {
+            Location::Reg8(reg) => self.registers.read_8(reg),
+            Location::Memory(addr) => self.read(bus, addr),
+        }
+    }
+    fn set_8(&mut self, bus: &mut impl Bus, location: Location, val: u8) {
+        match location {
+            Location::Reg8(reg) => self.registers.write_8(reg, val),
+            Location::Memory(addr) => self.write(bus, addr, val),
+        }
+    }
+    /// Pending interrupts, enabled in IE and requested in IF
+    fn pending_interrupts(&self, bus: &mut impl Bus) -> u8 {
+        bus.read_8(IE) & bus.read_8(IF) & 0x1F
+    }
+    /// Dispatch the highest priority pending interrupt, taking 5 M-cycles
+    fn interrupt(&mut self, bus: &mut impl Bus, pending: u8) {
+        self.ime = false;
+        self.idle(bus);
+        let pc = self.registers.read_16(Reg16::PC);
+        self.push(bus, pc);
+        let bit = pending.trailing_zeros() as u16;
+        let requested = bus.read_8(IF);
+        bus.write_8(IF, requested & !(1 << bit));
+        self.idle(bus);
+        self.registers.write_16(Reg16::PC, 0x40 + bit * 8);
+    }
+}
+
+pub trait Execution {
+    /// Execute one instruction, dispatch an interrupt or idle for an M-cycle while halted
+    /// Returns the T-cycles it took
+    fn step(&mut self, bus: &mut impl Bus) -> u32;
+    /// Execute an instruction that was fetched and decoded
+    /// Returns whether a conditional jump, call or return was taken, true for all others
+    fn exec(&mut self, bus: &mut impl Bus, instruction: Instruction) -> bool;
+}
+
+impl Execution for CPU {
+    fn step(&mut self, bus: &mut impl Bus) -> u32 {
+        self.cycles = 0;
+        let pending = self.pending_interrupts(bus);
+        if self.locked.is_some() || (self.halted && pending == 0) {
+            // Only an event can wake the CPU, skip to it in whole M-cycles
+            let cycles = bus
+                .cycles_to_event()
+                .map_or(4, |cycles| cycles.div_ceil(4).max(1) * 4);
+            bus.advance(cycles);
+            self.cycles += cycles;
+        } else if self.ime && pending != 0 {
+            self.halted = false;
+            self.interrupt(bus, pending);
+        } else {
+            self.halted = false;
+            if self.ime_pending {
+                self.ime_pending = false;
+                self.ime = true;
+            }
+            let (opcode, instruction) = self.pop_pc(bus);
+            let taken = self.exec(bus, instruction);
+            let expected = opcode.cycles[if taken { 0 } else { 1 }] as u32;
+            debug_assert_eq!(self.cycles, expected, "Cycle count of {}", instruction);
+        }
+        // VRAM DMA stalls the CPU while it copies
+        let stall = bus.take_stall_cycles();
+        bus.advance(stall);
+        self.cycles += stall;
+        self.cycles
+    }
+    fn exec(&mut self, bus: &mut impl Bus, instruction: Instruction) -> bool {
+        match instruction {
+            Instruction::Nop => {}
+            Instruction::Ld(dst, src) => {
+                let destination = self.locate(dst);
+                let source = self.locate(src);
+                let val = self.get_8(bus, source);
+                self.set_8(bus, destination, val);
+            }
+            Instruction::LdImm(dst, Imm8(val)) => {
+                let destination = self.locate(dst);
+                self.set_8(bus, destination, val);
+            }
+            Instruction::Ld16(reg, Imm16(val)) => self.registers.write_16(reg, val),
+            Instruction::LdSpHl => {
+                let hl = self.registers.read_16(Reg16::HL);
+                self.idle(bus);
+                self.registers.write_16(Reg16::SP, hl);
+            }
+            Instruction::LdHlSp(Rel8(offset)) => {
+                let (result, f) = alu::add_sp(self.registers.read_16(Reg16::SP), offset as u8);
+                self.idle(bus);
+                self.registers.write_16(Reg16::HL, result);
+                self.set_flags(f);
+            }
+            Instruction::LdAbsSp(Imm16(addr)) => {
+                let sp = self.registers.read_16(Reg16::SP);
+                self.write(bus, addr, sp as u8);
+                self.write(bus, addr.wrapping_add(1), (sp >> 8) as u8);
+            }
+            Instruction::Push(reg) => {
+                let val = self.registers.read_16(reg);
+                self.push(bus, val);
+            }
+            Instruction::Pop(reg) => {
+                let val = self.pop(bus);
+                self.registers.write_16(reg, val);
+            }
+            Instruction::Alu(op, src) => {
+                let location = self.locate(src);
+                let val = self.get_8(bus, location);
+                self.alu(op, val);
+            }
+            Instruction::AluImm(op, Imm8(val)) => self.alu(op, val),
+            Instruction::AddHl(reg) => {
+                let val = self.registers.read_16(reg);
+                let (result, f) = alu::add_16(self.registers.read_16(Reg16::HL), val, self.flags());
+                self.idle(bus);
+                self.registers.write_16(Reg16::HL, result);
+                self.set_flags(f);
+            }
+            Instruction::AddSp(Rel8(offset)) => {
+                let (result, f) = alu::add_sp(self.registers.read_16(Reg16::SP), offset as u8);
+                self.idle(bus);
+                self.idle(bus);
+                self.registers.write_16(Reg16::SP, result);
+                self.set_flags(f);
+            }
+            Instruction::Inc(target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                let (result, f) = alu::inc(val, self.flags());
+                self.set_8(bus, location, result);
+                self.set_flags(f);
+            }
+            Instruction::Dec(target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                let (result, f) = alu::dec(val, self.flags());
+                self.set_8(bus, location, result);
+                self.set_flags(f);
+            }
+            Instruction::Inc16(reg) => {
+                let val = self.registers.read_16(reg);
+                self.idle(bus);
+                self.registers.write_16(reg, val.wrapping_add(1));
+            }
+            Instruction::Dec16(reg) => {
+                let val = self.registers.read_16(reg);
+                self.idle(bus);
+                self.registers.write_16(reg, val.wrapping_sub(1));
+            }
+            Instruction::JpHl => {
+                let hl = self.registers.read_16(Reg16::HL);
+                self.registers.write_16(Reg16::PC, hl);
+            }
+            Instruction::Jp(cond, Imm16(target)) => {
+                if !self.condition(cond) {
+                    return false;
+                }
+                self.idle(bus);
+                self.registers.write_16(Reg16::PC, target);
+            }
+            Instruction::Jr(cond, Rel8(offset)) => {
+                if !self.condition(cond) {
+                    return false;
+                }
+                self.idle(bus);
+                let pc = self.registers.read_16(Reg16::PC);
+                self.registers
+                    .write_16(Reg16::PC, pc.wrapping_add(offset as u16));
+            }
+            Instruction::Call(cond, Imm16(target)) => {
+                if !self.condition(cond) {
+                    return false;
+                }
+                let pc = self.registers.read_16(Reg16::PC);
+                self.push(bus, pc);
+                self.registers.write_16(Reg16::PC, target);
+            }
+            Instruction::Ret(cond) => {
+                if cond.is_some() {
+                    self.idle(bus);
+                    if !self.condition(cond) {
+                        return false;
+                    }
+                }
+                let pc = self.pop(bus);
+                self.idle(bus);
+                self.registers.write_16(Reg16::PC, pc);
+            }
+            Instruction::Reti => {
+                let pc = self.pop(bus);
+                self.idle(bus);
+                self.registers.write_16(Reg16::PC, pc);
+                self.ime = true;
+            }
+            Instruction::Rst(vector) => {
+                let pc = self.registers.read_16(Reg16::PC);
+                self.push(bus, pc);
+                self.registers.write_16(Reg16::PC, vector as u16);
+            }
+            Instruction::Di => {
+                self.ime = false;
+                self.ime_pending = false;
+            }
+            Instruction::Ei => self.ime_pending = true,
+            Instruction::Halt => {
+                if !self.ime && self.pending_interrupts(bus) != 0 {
+                    self.halt_bug = true;
+                } else {
+                    self.halted = true;
+                }
+            }
+            Instruction::Stop => {
+                // The byte after STOP is skipped
+                let pc = self.registers.read_16(Reg16::PC);
+                self.registers.write_16(Reg16::PC, pc.wrapping_add(1));
+                // STOP switches speed in CGB mode when armed through KEY1
+                bus.speed_switch();
+            }
+            Instruction::Daa => {
+                let (result, f) = alu::daa(self.registers.read_8(Reg8::A), self.flags());
+                self.registers.write_8(Reg8::A, result);
+                self.set_flags(f);
+            }
+            Instruction::Cpl => {
+                let a = self.registers.read_8(Reg8::A);
+                self.registers.write_8(Reg8::A, !a);
+                self.set_flags(self.flags() | alu::N | alu::H);
+            }
+            Instruction::Scf => self.set_flags(self.flags() & alu::Z | alu::C),
+            Instruction::Ccf => self.set_flags(self.flags() & (alu::Z | alu::C) ^ alu::C),
+            Instruction::Rlca => self.rotate_a(ShiftOp::Rlc),
+            Instruction::Rrca => self.rotate_a(ShiftOp::Rrc),
+            Instruction::Rla => self.rotate_a(ShiftOp::Rl),
+            Instruction::Rra => self.rotate_a(ShiftOp::Rr),
+            Instruction::Shift(op, target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                let (result, f) = self.shift(op, val);
+                self.set_8(bus, location, result);
+                self.set_flags(f);
+            }
+            Instruction::Bit(n, target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                self.set_flags(alu::bit(n, val, self.flags()));
+            }
+            Instruction::Res(n, target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                self.set_8(bus, location, val & !(1 << n));
+            }
+            Instruction::Set(n, target) => {
+                let location = self.locate(target);
+                let val = self.get_8(bus, location);
+                self.set_8(bus, location, val | 1 << n);
+            }
+            Instruction::Illegal(_) => {
+                // Illegal opcodes hang the CPU until it is reset
+                self.locked = Some(self.registers.read_16(Reg16::PC).wrapping_sub(1));
+            }
+        }
+        true
+    }
+}
+
+/// Arithmetic and logic
+impl CPU {
+    /// ADD, ADC, SUB, SBC, AND, XOR, OR and CP of A with `val`
+    fn alu(&mut self, op: AluOp, val: u8) {
+        let a = self.registers.read_8(Reg8::A);
+        let carry = self.flags() & alu::C != 0;
+        let (result, f) = match op {
+            AluOp::Add => alu::add(a, val, false),
+            AluOp::Adc => alu::add(a, val, carry),
+            AluOp::Sub | AluOp::Cp => alu::sub(a, val, false),
+            AluOp::Sbc => alu::sub(a, val, carry),
+            AluOp::And => alu::and(a, val),
+            AluOp::Xor => alu::xor(a, val),
+            AluOp::Or => alu::or(a, val),
+        };
+        if op != AluOp::Cp {
+            self.registers.write_8(Reg8::A, result);
+        }
+        self.set_flags(f);
+    }
+    /// Rotate or shift `val`, returns the result and the new flags
+    fn shift(&self, op: ShiftOp, val: u8) -> (u8, u8) {
+        let f = self.flags();
+        match op {
+            ShiftOp::Rlc => alu::rlc(val),
+            ShiftOp::Rrc => alu::rrc(val),
+            ShiftOp::Rl => alu::rl(val, f),
+            ShiftOp::Rr => alu::rr(val, f),
+            ShiftOp::Sla => alu::sla(val),
+            ShiftOp::Sra => alu::sra(val),
+            ShiftOp::Swap => alu::swap(val),
+            ShiftOp::Srl => alu::srl(val),
+        }
+    }
+    /// RLCA, RRCA, RLA and RRA
+    fn rotate_a(&mut self, op: ShiftOp) {
+        let (result, f) = self.shift(op, self.registers.read_8(Reg8::A));
+        self.registers.write_8(Reg8::A, result);
+        // The accumulator rotates always clear the zero flag
+        self.set_flags(f & alu::C);
+    }
+}
+
+impl Savestate for CPU {
+    fn state(&mut self, state: &mut State) {
+        self.registers.state(state);
+        state.bool(&mut self.ime);
+        state.bool(&mut self.ime_pending);
+        state.bool(&mut self.halted);
+        state.bool(&mut self.halt_bug);
+        let mut locked = self.locked.is_some();
+        let mut addr = self.locked.unwrap_or(0);
+        state.bool(&mut locked);
+        state.u16(&mut addr);
+        self.locked = Some(addr).filter(|_| locked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mem::ROMAccess;
+    use super::super::rw::RWAccess;
+    use super::super::test_rom;
+    use super::super::timer::TIMA;
+    use super::*;
+
+    /// CPU about to run `program` from the post-boot state
+    fn cpu(program: &[u8]) -> (CPU, Memory) {
+        let mut memory = Memory::new();
+        memory
+            .load_rom_data(&test_rom::rom(program))
+            .expect("ERROR: Invalid test ROM");
+        let mut cpu = CPU::new(&mut memory);
+        cpu.registers.write_16(Reg16::PC, test_rom::PROGRAM as u16);
+        (cpu, memory)
+    }
+
+    /// T-cycles taken by each of the next `steps` steps
+    fn steps(cpu: &mut CPU, memory: &mut Memory, steps: usize) -> Vec<u32> {
+        (0..steps).map(|_| cpu.step(memory)).collect()
+    }
+
+    #[test]
+    fn branch_cycles() {
+        let (mut cpu, mut memory) = cpu(&[
+            0xAF, // xor a, sets Z
+            0x20, 0x00, // jr nz, not taken
+            0x28, 0x00, // jr z, taken
+            0xC2, 0x00, 0x00, // jp nz, not taken
+            0xCA, 0x5B, 0x01, // jp z, $015B taken
+            0xC4, 0x00, 0x00, // call nz, not taken
+            0xCC, 0x70, 0x01, // call z, $0170 taken
+            0xCD, 0x72, 0x01, // call $0172
+            0xC3, 0x67, 0x01, // jp $0167
+            0x18, 0xFE, // jr @
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding up to $0170
+            0xC0, // ret nz, not taken
+            0xC8, // ret z, taken
+            0xC9, // ret
+        ]);
+        assert_eq!(
+            steps(&mut cpu, &mut memory, 13),
+            [4, 8, 12, 12, 16, 12, 24, 8, 20, 24, 16, 16, 12]
+        );
+        assert_eq!(cpu.pc(), 0x0167);
+    }
+
+    #[test]
+    fn interrupt_dispatch_after_ei_delay() {
+        let (mut cpu, mut memory) = cpu(&[
+            0x3E, 0x01, // ld a, 1
+            0xE0, 0xFF, // ldh [IE], a
+            0xE0, 0x0F, // ldh [IF], a
+            0xFB, // ei
+            0xF3, // di
+            0xFB, // ei
+            0x00, // nop
+            0x00, // nop
+        ]);
+        steps(&mut cpu, &mut memory, 4);
+        // DI right after EI cancels it
+        assert_eq!(steps(&mut cpu, &mut memory, 2), [4, 4]);
+        assert_eq!(cpu.pc(), 0x0159);
+        // The instruction after EI runs before interrupts are enabled
+        assert_eq!(steps(&mut cpu, &mut memory, 1), [4]);
+        assert_eq!(cpu.pc(), 0x015A);
+        // Dispatch takes 5 M-cycles and pushes the address of the next instruction
+        assert_eq!(steps(&mut cpu, &mut memory, 1), [20]);
+        assert_eq!(cpu.pc(), 0x0040);
+        let sp = cpu.registers.read_16(Reg16::SP);
+        assert_eq!(memory.read_16(sp), 0x015A);
+        assert_eq!(memory.read_8(IF) & 1, 0);
+        assert!(!cpu.ime);
+    }
+
+    #[test]
+    fn halt_bug_runs_next_byte_twice() {
+        let (mut cpu, mut memory) = cpu(&[
+            0x3E, 0x01, // ld a, 1
+            0xE0, 0xFF, // ldh [IE], a
+            0xE0, 0x0F, // ldh [IF], a
+            0x76, // halt, with IME off and an interrupt pending
+            0x3C, // inc a
+            0x18, 0xFE, // jr @
+        ]);
+        steps(&mut cpu, &mut memory, 6);
+        assert!(!cpu.is_halted());
+        assert_eq!(cpu.registers.read_8(Reg8::A), 3);
+        assert_eq!(cpu.pc(), 0x0158);
+    }
+
+    /// Write TIMA right as it overflows, `delay` NOPs after resetting DIV
+    /// Returns TIMA and whether the timer interrupt was requested right after the write
+    fn tima_write_race(delay: usize) -> (u8, bool) {
+        let mut program = vec![
+            0xAF, // xor a
+            0xE0, 0x0F, // ldh [IF], a
+            0x3E, 0x05, // ld a, 5
+            0xE0, 0x07, // ldh [TAC], a, TIMA counts every 16 T-cycles
+            0x3E, 0x42, // ld a, $42
+            0xE0, 0x06, // ldh [TMA], a
+            0x21, 0x05, 0xFF, // ld hl, TIMA
+            0x06, 0x99, // ld b, $99
+            0x3E, 0xFF, // ld a, $FF
+            0xE0, 0x04, // ldh [DIV], a, the counter is 0 after the write
+            0x77, // ld [hl], a, TIMA is $FF at 8 T-cycles
+        ];
+        program.extend(std::iter::repeat_n(0x00, delay));
+        // ld [hl], b, writes at 16 + 4 * delay T-cycles
+        program.push(0x70);
+        let (mut cpu, mut memory) = cpu(&program);
+        steps(&mut cpu, &mut memory, 12 + delay);
+        memory.sync();
+        let requested = memory.read_8(IF) & 1 << 2 != 0;
+        (memory.read_8(TIMA), requested)
+    }
+
+    #[test]
+    fn tima_write_race_is_resolved_per_access() {
+        // TIMA overflows to 0 at 16 T-cycles, a write in that M-cycle cancels the reload
+        assert_eq!(tima_write_race(0), (0x99, false));
+        // TMA is reloaded at 20 T-cycles and a write in that M-cycle is ignored
+        assert_eq!(tima_write_race(1), (0x42, true));
+    }
+}
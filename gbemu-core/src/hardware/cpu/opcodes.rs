@@ -0,0 +1,51 @@
+/// Opcode tables
+/// Generated by build.rs from resources/opcode/opcodes.json, indexed by opcode.
+/// They describe the length, timing and flags of every opcode, decoding into an `Instruction` is
+/// done by `Instruction::unprefixed` and `Instruction::cb_prefixed`.
+#[derive(Clone, Copy, Debug)]
+pub struct Opcode {
+    pub mnemonic: &'static str,
+    /// Length including the CB prefix and the immediate operands
+    pub bytes: u8,
+    /// T-cycles when a condition is met and when it is not
+    pub cycles: [u8; 2],
+    pub operands: [Operand; 3],
+    pub immediate: bool,
+    pub flags: Flags,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Operand {
+    pub name: &'static str,
+    pub bytes: u8,
+    pub immediate: bool,
+    pub increment: bool,
+    pub decrement: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Flags {
+    pub z: &'static str,
+    pub n: &'static str,
+    pub h: &'static str,
+    pub c: &'static str,
+}
+
+/// Fills the operands of opcodes taking less than 3
+const NULL_OPERAND: Operand = Operand {
+    name: "NULL",
+    bytes: 0,
+    immediate: false,
+    increment: false,
+    decrement: false,
+};
+
+include!(concat!(env!("OUT_DIR"), "/opcodes.rs"));
+
+pub fn unprefixed_opcode_get(opcode: u8) -> &'static Opcode {
+    &UNPREFIXED_OPCODES[opcode as usize]
+}
+
+pub fn cb_prefixed_opcode_get(opcode: u8) -> &'static Opcode {
+    &CB_PREFIXED_OPCODES[opcode as usize]
+}
@@ -0,0 +1,243 @@
+use super::super::state::{Savestate, State};
+
+// Registers are 8-bit and 16-bit values that are used to store data and perform operations.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The 8-bit registers of the CPU, by their index in the register file
+pub enum Reg8 {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+    E = 4,
+    H = 5,
+    L = 6,
+    /// Flags, the low nibble always reads 0
+    F = 7,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The 16-bit registers of the CPU, pairs are the high register followed by the low one
+pub enum Reg16 {
+    AF,
+    BC,
+    DE,
+    HL, // Typically used for memory addressing
+    SP, // Stack Pointer
+    PC, // Program Counter
+}
+
+/// Indices of the high bytes of SP and PC in the register file, the low bytes follow them
+const SP: usize = 8;
+const PC: usize = 10;
+
+impl Reg16 {
+    /// Indices of the high and low bytes in the register file
+    fn indices(self) -> (usize, usize) {
+        match self {
+            Reg16::AF => (Reg8::A as usize, Reg8::F as usize),
+            Reg16::BC => (Reg8::B as usize, Reg8::C as usize),
+            Reg16::DE => (Reg8::D as usize, Reg8::E as usize),
+            Reg16::HL => (Reg8::H as usize, Reg8::L as usize),
+            Reg16::SP => (SP, SP + 1),
+            Reg16::PC => (PC, PC + 1),
+        }
+    }
+}
+
+/// Flag Register
+/// STORED IN F REGISTER
+/// BIT 0-3: Unused
+/// BIT 4: Carry Flag
+/// BIT 5: Half Carry Flag
+/// BIT 6: Subtract Flag
+/// BIT 7: Zero Flag
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Flags that can be set in the F register
+pub enum Flag {
+    Zero = 7,      // Z
+    Subtract = 6,  // N
+    HalfCarry = 5, // H
+    Carry = 4,     // C
+}
+
+/// Bits of F that hold flags
+const FLAGS_MASK: u8 = 0xF0;
+
+/// Register file
+/// A B C D E H L F, then SP and PC high byte first, which is also how they are saved
+pub struct Registers {
+    registers: [u8; 12],
+}
+
+impl Registers {
+    /// Create a new Registers struct
+    /// All registers are initialized to 0, which is where the boot ROM starts
+    pub fn new() -> Registers {
+        Registers { registers: [0; 12] }
+    }
+    /// Read 8-bit register
+    pub fn read_8(&self, reg: Reg8) -> u8 {
+        self.registers[reg as usize]
+    }
+    /// Write 8-bit register, the low nibble of F is dropped
+    pub fn write_8(&mut self, reg: Reg8, val: u8) {
+        self.registers[reg as usize] = if reg == Reg8::F {
+            val & FLAGS_MASK
+        } else {
+            val
+        };
+    }
+    /// Read 16-bit register
+    pub fn read_16(&self, reg: Reg16) -> u16 {
+        let (hi, lo) = reg.indices();
+        u16::from_be_bytes([self.registers[hi], self.registers[lo]])
+    }
+    /// Write 16-bit register, the low nibble of F is dropped
+    pub fn write_16(&mut self, reg: Reg16, val: u16) {
+        let (hi, lo) = reg.indices();
+        let [high, low] = val.to_be_bytes();
+        self.registers[hi] = high;
+        self.registers[lo] = if reg == Reg16::AF {
+            low & FLAGS_MASK
+        } else {
+            low
+        };
+    }
+    /// Read a flag from F register
+    pub fn flag(&self, flag: Flag) -> bool {
+        self.read_8(Reg8::F) & 1 << flag as u8 != 0
+    }
+    /// Write a flag to F register
+    pub fn set_flag(&mut self, flag: Flag, set: bool) {
+        let f = self.read_8(Reg8::F) & !(1 << flag as u8);
+        self.write_8(Reg8::F, f | (set as u8) << flag as u8);
+    }
+    pub fn zero(&self) -> bool {
+        self.flag(Flag::Zero)
+    }
+    pub fn set_zero(&mut self, set: bool) {
+        self.set_flag(Flag::Zero, set);
+    }
+    pub fn subtract(&self) -> bool {
+        self.flag(Flag::Subtract)
+    }
+    pub fn set_subtract(&mut self, set: bool) {
+        self.set_flag(Flag::Subtract, set);
+    }
+    pub fn half_carry(&self) -> bool {
+        self.flag(Flag::HalfCarry)
+    }
+    pub fn set_half_carry(&mut self, set: bool) {
+        self.set_flag(Flag::HalfCarry, set);
+    }
+    pub fn carry(&self) -> bool {
+        self.flag(Flag::Carry)
+    }
+    pub fn set_carry(&mut self, set: bool) {
+        self.set_flag(Flag::Carry, set);
+    }
+}
+
+impl Default for Registers {
+    fn default() -> Registers {
+        Registers::new()
+    }
+}
+
+impl Savestate for Registers {
+    fn state(&mut self, state: &mut State) {
+        state.bytes(&mut self.registers);
+        // A loaded F may have garbage in its low nibble
+        self.registers[Reg8::F as usize] &= FLAGS_MASK;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIRS: [(Reg16, Option<(Reg8, Reg8)>); 6] = [
+        (Reg16::AF, Some((Reg8::A, Reg8::F))),
+        (Reg16::BC, Some((Reg8::B, Reg8::C))),
+        (Reg16::DE, Some((Reg8::D, Reg8::E))),
+        (Reg16::HL, Some((Reg8::H, Reg8::L))),
+        (Reg16::SP, None),
+        (Reg16::PC, None),
+    ];
+
+    #[test]
+    fn pairs_round_trip() {
+        for (reg, _) in PAIRS {
+            let mut registers = Registers::new();
+            registers.write_16(reg, 0x1230);
+            assert_eq!(registers.read_16(reg), 0x1230, "{:?}", reg);
+            registers.write_16(reg, 0xFEDC);
+            let expected = if reg == Reg16::AF { 0xFED0 } else { 0xFEDC };
+            assert_eq!(registers.read_16(reg), expected, "{:?}", reg);
+        }
+    }
+
+    #[test]
+    fn pairs_are_made_of_their_halves() {
+        for (reg, halves) in PAIRS {
+            let Some((hi, lo)) = halves else { continue };
+            let mut registers = Registers::new();
+            registers.write_16(reg, 0xA5C0);
+            assert_eq!(registers.read_8(hi), 0xA5, "{:?}", reg);
+            assert_eq!(registers.read_8(lo), 0xC0, "{:?}", reg);
+            registers.write_8(hi, 0x3C);
+            registers.write_8(lo, 0x90);
+            assert_eq!(registers.read_16(reg), 0x3C90, "{:?}", reg);
+        }
+    }
+
+    #[test]
+    fn pairs_do_not_overlap() {
+        let mut registers = Registers::new();
+        for (i, (reg, _)) in PAIRS.into_iter().enumerate() {
+            registers.write_16(reg, 0x1110 * (i as u16 + 1));
+        }
+        for (i, (reg, _)) in PAIRS.into_iter().enumerate() {
+            assert_eq!(registers.read_16(reg), 0x1110 * (i as u16 + 1), "{:?}", reg);
+        }
+    }
+
+    #[test]
+    fn f_low_nibble_is_masked() {
+        let mut registers = Registers::new();
+        registers.write_8(Reg8::F, 0xFF);
+        assert_eq!(registers.read_8(Reg8::F), 0xF0);
+        registers.write_16(Reg16::AF, 0x000F);
+        assert_eq!(registers.read_16(Reg16::AF), 0x0000);
+
+        let mut garbage = Registers::new();
+        garbage.registers[Reg8::F as usize] = 0xFF;
+        let saved = {
+            let mut state = State::saver();
+            garbage.state(&mut state);
+            state.finish().unwrap()
+        };
+        let mut state = State::loader(&saved);
+        registers.state(&mut state);
+        assert_eq!(registers.read_8(Reg8::F), 0xF0);
+    }
+
+    #[test]
+    fn flags() {
+        let mut registers = Registers::new();
+        registers.set_zero(true);
+        registers.set_carry(true);
+        assert_eq!(registers.read_8(Reg8::F), 0x90);
+        assert!(registers.zero() && registers.carry());
+        assert!(!registers.subtract() && !registers.half_carry());
+        registers.set_zero(false);
+        registers.set_subtract(true);
+        registers.set_half_carry(true);
+        assert_eq!(registers.read_8(Reg8::F), 0x70);
+        assert!(!registers.zero() && registers.subtract() && registers.half_carry());
+        registers.set_carry(false);
+        assert_eq!(registers.read_8(Reg8::F), 0x60);
+    }
+}
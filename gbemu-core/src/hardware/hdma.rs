@@ -0,0 +1,211 @@
+use super::state::{Savestate, State};
+
+/// CGB VRAM DMA registers
+/// Documented at: https://gbdev.io/pandocs/CGB_Registers.html#lcd-vram-dma-transfers
+/// HDMA1 [0xFF51], HDMA2 [0xFF52]: Source address, high and low (lower 4 bits ignored)
+/// HDMA3 [0xFF53], HDMA4 [0xFF54]: Destination in VRAM, high and low (only bits 4-12 used)
+/// HDMA5 [0xFF55]: Length, mode and start
+/// BIT 0-6: Number of 16 byte blocks to copy, minus 1
+/// BIT 7: Mode (0 = general purpose, 1 = HBlank)
+pub const HDMA1: u16 = 0xFF51;
+pub const HDMA2: u16 = 0xFF52;
+pub const HDMA3: u16 = 0xFF53;
+pub const HDMA4: u16 = 0xFF54;
+pub const HDMA5: u16 = 0xFF55;
+
+/// Bytes copied per block
+pub const BLOCK_SIZE: u16 = 0x10;
+/// T-cycles at normal speed the CPU is stalled per block, twice as many CPU T-cycles in double speed
+pub const BLOCK_CYCLES: u32 = 32;
+
+/// VRAM DMA
+/// General purpose DMA copies every block at once while the CPU is stalled.
+/// HBlank DMA copies one block at the start of every HBlank and can be cancelled by writing
+/// HDMA5 with bit 7 cleared. While it runs, HDMA5 reads the remaining blocks minus 1 with bit 7
+/// cleared, once cancelled bit 7 is set, and once finished it reads 0xFF.
+pub struct Hdma {
+    source: u16,
+    dest: u16,
+    /// Blocks left to copy, minus 1
+    remaining: u8,
+    /// Whether an HBlank DMA is running
+    hblank: bool,
+}
+
+impl Hdma {
+    /// Create a new Hdma
+    pub fn new() -> Hdma {
+        Hdma {
+            source: 0,
+            dest: 0,
+            remaining: 0x7F,
+            hblank: false,
+        }
+    }
+
+    /// Read a VRAM DMA register, only HDMA5 is readable
+    pub fn read(&self, addr: u16) -> u8 {
+        match addr {
+            HDMA5 => ((!self.hblank) as u8) << 7 | self.remaining,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write a VRAM DMA register
+    /// Returns the number of blocks to copy when a general purpose DMA is started
+    pub fn write(&mut self, addr: u16, val: u8) -> Option<u16> {
+        match addr {
+            HDMA1 => self.source = (self.source & 0x00FF) | (val as u16) << 8,
+            HDMA2 => self.source = (self.source & 0xFF00) | (val & 0xF0) as u16,
+            HDMA3 => self.dest = (self.dest & 0x00FF) | ((val & 0x1F) as u16) << 8,
+            HDMA4 => self.dest = (self.dest & 0xFF00) | (val & 0xF0) as u16,
+            HDMA5 => {
+                if self.hblank && val & 0x80 == 0 {
+                    // Cancel the running HBlank DMA
+                    self.hblank = false;
+                    return None;
+                }
+                self.remaining = val & 0x7F;
+                if val & 0x80 != 0 {
+                    self.hblank = true;
+                } else {
+                    return Some(self.remaining as u16 + 1);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Whether an HBlank DMA is running
+    pub fn hblank_active(&self) -> bool {
+        self.hblank
+    }
+
+    /// Source and VRAM destination of the next block, advancing past it
+    /// Ends the HBlank DMA after its last block
+    pub fn next_block(&mut self) -> (u16, u16) {
+        let block = (self.source, 0x8000 | self.dest);
+        self.source = self.source.wrapping_add(BLOCK_SIZE);
+        self.dest = (self.dest + BLOCK_SIZE) & 0x1FF0;
+        if self.remaining == 0 {
+            self.remaining = 0x7F;
+            self.hblank = false;
+        } else {
+            self.remaining -= 1;
+        }
+        block
+    }
+}
+
+impl Default for Hdma {
+    fn default() -> Hdma {
+        Hdma::new()
+    }
+}
+
+impl Savestate for Hdma {
+    fn state(&mut self, state: &mut State) {
+        state.u16(&mut self.source);
+        state.u16(&mut self.dest);
+        state.u8(&mut self.remaining);
+        state.bool(&mut self.hblank);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mem::{Memory, ROMAccess, KEY1};
+    use super::super::ppu::LCDC;
+    use super::super::rw::RWAccess;
+    use super::super::test_rom::cgb_rom;
+    use super::*;
+
+    /// Memory in CGB mode with `blocks` blocks of distinct data at 0xC000
+    fn memory(blocks: u16) -> Memory {
+        let mut memory = Memory::new();
+        memory.load_rom_data(&cgb_rom(&[])).unwrap();
+        assert!(memory.is_cgb());
+        for i in 0..blocks * BLOCK_SIZE {
+            memory.write_8(0xC000 + i, i as u8 ^ 0x5A);
+        }
+        memory.write_8(HDMA1, 0xC0);
+        memory.write_8(HDMA2, 0x00);
+        memory.write_8(HDMA3, 0x00);
+        memory.write_8(HDMA4, 0x00);
+        memory
+    }
+
+    /// Number of blocks copied to 0x8000 from the data at 0xC000
+    fn copied(memory: &Memory, blocks: u16) -> u16 {
+        (0..blocks)
+            .take_while(|block| {
+                (0..BLOCK_SIZE).all(|i| {
+                    let offset = block * BLOCK_SIZE + i;
+                    memory.read_8(0x8000 + offset) == memory.read_8(0xC000 + offset)
+                })
+            })
+            .count() as u16
+    }
+
+    /// Advance until HDMA5 changes, returns its new value
+    fn next_hblank(memory: &mut Memory) -> u8 {
+        let old = memory.read_8(HDMA5);
+        for _ in 0..456 {
+            memory.advance(4);
+            memory.sync();
+            if memory.read_8(HDMA5) != old {
+                return memory.read_8(HDMA5);
+            }
+        }
+        panic!("ERROR: No HBlank within a line");
+    }
+
+    #[test]
+    fn general_purpose_dma_copies_at_once() {
+        let mut memory = memory(4);
+        memory.write_8(LCDC, 0x00);
+        memory.write_8(HDMA5, 0x03);
+        assert_eq!(copied(&memory, 4), 4);
+        assert_eq!(memory.take_stall_cycles(), 4 * BLOCK_CYCLES);
+        assert_eq!(memory.read_8(HDMA5), 0xFF);
+
+        // In double speed the CPU is stalled for twice as many of its T-cycles
+        let mut memory = self::memory(2);
+        memory.write_8(KEY1, 0x01);
+        assert!(memory.speed_switch());
+        memory.write_8(HDMA5, 0x01);
+        assert_eq!(copied(&memory, 2), 2);
+        assert_eq!(memory.take_stall_cycles(), (2 * BLOCK_CYCLES) << 1);
+    }
+
+    #[test]
+    fn hblank_dma_copies_a_block_per_hblank() {
+        let mut memory = memory(3);
+        memory.write_8(HDMA5, 0x82);
+        assert_eq!(memory.read_8(HDMA5), 0x02);
+        assert_eq!(copied(&memory, 3), 0);
+
+        assert_eq!(next_hblank(&mut memory), 0x01);
+        assert_eq!(copied(&memory, 3), 1);
+        assert_eq!(memory.take_stall_cycles(), BLOCK_CYCLES);
+        assert_eq!(next_hblank(&mut memory), 0x00);
+        assert_eq!(copied(&memory, 3), 2);
+        assert_eq!(next_hblank(&mut memory), 0xFF);
+        assert_eq!(copied(&memory, 3), 3);
+    }
+
+    #[test]
+    fn hblank_dma_is_cancelled_by_bit_7_clear() {
+        let mut memory = memory(4);
+        memory.write_8(HDMA5, 0x83);
+        assert_eq!(next_hblank(&mut memory), 0x02);
+        memory.write_8(HDMA5, 0x00);
+        // The remaining length is kept with bit 7 set
+        assert_eq!(memory.read_8(HDMA5), 0x82);
+        memory.advance(4 * 456);
+        memory.sync();
+        assert_eq!(copied(&memory, 4), 1);
+        assert_eq!(memory.read_8(HDMA5), 0x82);
+    }
+}
@@ -0,0 +1,19 @@
+/// Interrupt Flag Register (IF) [0xFF0F] and Interrupt Enable Register (IE) [0xFFFF]
+/// BIT 0: VBlank
+/// BIT 1: LCD STAT
+/// BIT 2: Timer
+/// BIT 3: Serial
+/// BIT 4: Joypad
+/// BIT 5-7: Unused
+pub const IF: u16 = 0xFF0F;
+pub const IE: u16 = 0xFFFF;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Interrupt sources, valued by their bit in IF and IE
+pub enum Interrupt {
+    VBlank = 0,
+    LCDStat = 1,
+    Timer = 2,
+    Serial = 3,
+    Joypad = 4,
+}
@@ -0,0 +1,274 @@
+use super::state::{Savestate, State};
+
+/// Joypad register P1/JOYP [0xFF00]
+/// Documented at: https://gbdev.io/pandocs/Joypad_Input.html
+/// BIT 0: Right or A (0 = pressed)
+/// BIT 1: Left or B (0 = pressed)
+/// BIT 2: Up or Select (0 = pressed)
+/// BIT 3: Down or Start (0 = pressed)
+/// BIT 4: Select direction buttons (0 = selected)
+/// BIT 5: Select action buttons (0 = selected)
+/// BIT 6-7: Unused
+pub const P1: u16 = 0xFF00;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Buttons of the Gameboy
+/// Direction buttons occupy the low nibble and action buttons the high nibble of the pressed mask
+pub enum Button {
+    Right = 0,
+    Left = 1,
+    Up = 2,
+    Down = 3,
+    A = 4,
+    B = 5,
+    Select = 6,
+    Start = 7,
+}
+
+impl Button {
+    /// The direction on the other side of the D-pad, if any
+    fn opposite(&self) -> Option<Button> {
+        match self {
+            Button::Right => Some(Button::Left),
+            Button::Left => Some(Button::Right),
+            Button::Up => Some(Button::Down),
+            Button::Down => Some(Button::Up),
+            _ => None,
+        }
+    }
+}
+
+/// Joypad
+/// Holds the state of the select lines written by the game and the buttons pressed by the host.
+/// The joypad interrupt is requested whenever one of the P10-P13 input lines goes from high to low.
+///
+/// With the Super Game Boy multiplayer mode, up to four joypads are connected. The joypad read is
+/// switched to the next player on the rising edge of P15, and while no line is selected the lower
+/// nibble reads 0xF minus the current player.
+pub struct Joypad {
+    /// Bits 4 and 5 of P1 as last written
+    select: u8,
+    /// Pressed buttons of each player, one bit per `Button`
+    pressed: [u8; 4],
+    /// Number of joypads connected, 1, 2 or 4
+    players: usize,
+    /// Player whose joypad is read
+    player: usize,
+    /// Whether opposing directions (Left+Right, Up+Down) may be held at once
+    allow_opposing: bool,
+    interrupt: bool,
+}
+
+impl Joypad {
+    /// Create a new Joypad
+    /// No buttons are pressed, no lines are selected and opposing directions are blocked
+    pub fn new() -> Joypad {
+        Joypad {
+            select: 0x30,
+            pressed: [0; 4],
+            players: 1,
+            player: 0,
+            allow_opposing: false,
+            interrupt: false,
+        }
+    }
+
+    /// Allow or block impossible combinations such as Left+Right
+    /// When blocked, pressing a direction releases its opposite
+    pub fn set_allow_opposing(&mut self, allow: bool) {
+        self.allow_opposing = allow;
+    }
+
+    /// Set the number of connected joypads, 1, 2 or 4, other numbers are ignored
+    pub fn set_players(&mut self, players: usize) {
+        if matches!(players, 1 | 2 | 4) {
+            self.players = players;
+            self.player = 0;
+        }
+    }
+
+    /// Number of connected joypads
+    pub fn players(&self) -> usize {
+        self.players
+    }
+
+    /// Press a button of the first player
+    pub fn press(&mut self, button: Button) {
+        self.press_player(0, button);
+    }
+
+    /// Release a button of the first player
+    pub fn release(&mut self, button: Button) {
+        self.release_player(0, button);
+    }
+
+    /// Whether a button of the first player is currently pressed
+    pub fn is_pressed(&self, button: Button) -> bool {
+        self.is_pressed_player(0, button)
+    }
+
+    /// Press a button of a player, 0-3, other players are ignored
+    pub fn press_player(&mut self, player: usize, button: Button) {
+        if player >= self.pressed.len() {
+            return;
+        }
+        let old = self.lines();
+        if !self.allow_opposing {
+            if let Some(opposite) = button.opposite() {
+                self.pressed[player] &= !(1 << opposite as u8);
+            }
+        }
+        self.pressed[player] |= 1 << button as u8;
+        self.update_interrupt(old);
+    }
+
+    /// Release a button of a player, 0-3, other players are ignored
+    pub fn release_player(&mut self, player: usize, button: Button) {
+        if let Some(pressed) = self.pressed.get_mut(player) {
+            *pressed &= !(1 << button as u8);
+        }
+    }
+
+    /// Whether a button of a player, 0-3, is currently pressed, never for other players
+    pub fn is_pressed_player(&self, player: usize, button: Button) -> bool {
+        self.pressed
+            .get(player)
+            .is_some_and(|pressed| pressed & (1 << button as u8) != 0)
+    }
+
+    /// Returns and clears a pending joypad interrupt request
+    pub fn take_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.interrupt)
+    }
+
+    /// State of the P10-P13 input lines, active-low
+    fn lines(&self) -> u8 {
+        let pressed = self.pressed[self.player];
+        if self.select == 0x30 && self.players > 1 {
+            return 0x0F - self.player as u8;
+        }
+        let mut lines = 0x0F;
+        if self.select & 0x10 == 0 {
+            lines &= !(pressed & 0x0F);
+        }
+        if self.select & 0x20 == 0 {
+            lines &= !(pressed >> 4);
+        }
+        lines
+    }
+
+    /// Request an interrupt if any input line went from high to low
+    fn update_interrupt(&mut self, old: u8) {
+        if old & !self.lines() != 0 {
+            self.interrupt = true;
+        }
+    }
+
+    /// Read P1
+    pub fn read(&self) -> u8 {
+        0xC0 | self.select | self.lines()
+    }
+
+    /// Write P1, only the select lines are writable
+    pub fn write(&mut self, val: u8) {
+        let old = self.lines();
+        if self.players > 1 && self.select & 0x20 == 0 && val & 0x20 != 0 {
+            self.player = (self.player + 1) % self.players;
+        }
+        self.select = val & 0x30;
+        self.update_interrupt(old);
+    }
+}
+
+impl Default for Joypad {
+    fn default() -> Joypad {
+        Joypad::new()
+    }
+}
+
+/// The buttons held and whether opposing directions are allowed are up to the frontend and not
+/// part of the state
+impl Savestate for Joypad {
+    fn state(&mut self, state: &mut State) {
+        state.u8(&mut self.select);
+        state.usize(&mut self.players);
+        state.usize(&mut self.player);
+        state.check(
+            matches!(self.players, 1 | 2 | 4) && self.player < self.players,
+            "joypad player out of range",
+        );
+        state.bool(&mut self.interrupt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interrupt_on_high_to_low_only() {
+        let mut joypad = Joypad::new();
+        // Nothing selected, pressing does not change the lines
+        joypad.press(Button::A);
+        assert!(!joypad.take_interrupt());
+        // Selecting the action buttons pulls P10 low
+        joypad.write(0x10);
+        assert!(joypad.take_interrupt());
+        assert_eq!(joypad.read(), 0xDE);
+        // Releasing raises the line, which does not request the interrupt
+        joypad.release(Button::A);
+        assert!(!joypad.take_interrupt());
+        assert_eq!(joypad.read(), 0xDF);
+        joypad.press(Button::Start);
+        assert!(joypad.take_interrupt());
+        // A line that is already low does not request it again
+        joypad.press(Button::Start);
+        assert!(!joypad.take_interrupt());
+        // Direction buttons are not selected
+        joypad.press(Button::Down);
+        assert!(!joypad.take_interrupt());
+        assert_eq!(joypad.read(), 0xD7);
+        // Selecting them pulls their line low, which is already low
+        joypad.write(0x00);
+        assert!(!joypad.take_interrupt());
+        assert_eq!(joypad.read(), 0xC7);
+    }
+
+    #[test]
+    fn opposing_directions_are_blocked_unless_allowed() {
+        let mut joypad = Joypad::new();
+        joypad.write(0x20);
+        joypad.press(Button::Left);
+        joypad.press(Button::Right);
+        assert!(!joypad.is_pressed(Button::Left));
+        assert!(joypad.is_pressed(Button::Right));
+        assert_eq!(joypad.read() & 0x0F, 0x0E);
+        joypad.press(Button::Up);
+        joypad.press(Button::Down);
+        assert!(!joypad.is_pressed(Button::Up));
+        assert_eq!(joypad.read() & 0x0F, 0x06);
+
+        joypad.set_allow_opposing(true);
+        joypad.press(Button::Left);
+        joypad.press(Button::Up);
+        assert!(joypad.is_pressed(Button::Right));
+        assert!(joypad.is_pressed(Button::Down));
+        assert_eq!(joypad.read() & 0x0F, 0x00);
+    }
+
+    #[test]
+    fn out_of_range_players_are_ignored() {
+        let mut joypad = Joypad::new();
+        joypad.press_player(4, Button::A);
+        joypad.release_player(4, Button::A);
+        assert!(!joypad.is_pressed_player(4, Button::A));
+        for players in [0, 3, 5] {
+            joypad.set_players(players);
+            assert_eq!(joypad.players(), 1);
+        }
+        joypad.set_players(4);
+        assert_eq!(joypad.players(), 4);
+        joypad.press_player(3, Button::B);
+        assert!(joypad.is_pressed_player(3, Button::B));
+    }
+}
@@ -0,0 +1,965 @@
+use super::{
+    apu::{self, Apu},
+    boot::{self, BootRom},
+    cache::{CacheMode, CachedInstruction, InstructionCache},
+    compat::CompatPalette,
+    cpu::{
+        instruction::Instruction,
+        opcodes::{cb_prefixed_opcode_get, unprefixed_opcode_get},
+    },
+    hdma::{self, Hdma},
+    interrupt::{Interrupt, IF},
+    joypad::{self, Joypad},
+    model::Model,
+    ppu::{self, Ppu},
+    rw::RWAccess,
+    scheduler::{Event, Scheduler},
+    serial::{self, link::SerialLink, Serial},
+    sgb::Sgb,
+    state::{Savestate, State},
+    timer::{self, Timer},
+};
+use std::fs;
+use std::io;
+
+/// CGB registers
+/// Documented at: https://gbdev.io/pandocs/CGB_Registers.html
+/// KEY1 [0xFF4D]: Speed switch, BIT 0: switch armed, BIT 7: current speed (1 = double speed)
+/// VBK [0xFF4F]: VRAM bank, BIT 0
+/// SVBK [0xFF70]: WRAM bank for [0xD000..0xDFFF], BIT 0-2 (0 selects bank 1)
+pub const KEY1: u16 = 0xFF4D;
+/// OAM DMA [0xFF46]: Copies 160 bytes from (value << 8) to OAM
+pub const DMA: u16 = 0xFF46;
+pub const VBK: u16 = 0xFF4F;
+pub const SVBK: u16 = 0xFF70;
+
+pub struct Memory {
+    rom_metadata: ROMMetadata,
+    data: [u8; 0x10000],
+    /// Boot ROM mapped over the cartridge, removed when BOOT is written
+    boot_rom: Option<BootRom>,
+    /// Whether the cartridge runs in CGB mode
+    cgb: bool,
+    /// Hardware model, selected from the cartridge header unless requested
+    model: Model,
+    requested_model: Option<Model>,
+    /// Palette given to a DMG cartridge in compatibility mode
+    compat_palette: Option<CompatPalette>,
+    /// Work RAM [0xC000..0xDFFF], bank 0 is fixed and banks 1-7 are switchable in CGB mode
+    wram: [[u8; 0x1000]; 8],
+    wram_bank: usize,
+    /// Whether the CPU runs at double speed
+    double_speed: bool,
+    /// Whether the next STOP switches speed
+    speed_switch_armed: bool,
+    /// Set in double speed when a T-cycle of the CPU is left over, which the components at
+    /// normal speed run on the next one
+    half_cycle: bool,
+    /// Time of the CPU clock and of the components, which are run lazily up to it
+    scheduler: Scheduler,
+    /// Time the components were run up to
+    synced: u64,
+    hdma: Hdma,
+    /// T-cycles the CPU has to stall for, taken by the CPU
+    stall_cycles: u32,
+    timer: Timer,
+    ppu: Ppu,
+    joypad: Joypad,
+    serial: Serial,
+    apu: Apu,
+    /// Super Game Boy, present when the cartridge supports it
+    sgb: Option<Sgb>,
+    /// Instructions decoded by the CPU
+    cache: InstructionCache,
+}
+
+impl Memory {
+    pub fn new() -> Memory {
+        Memory {
+            rom_metadata: ROMMetadata {
+                // ASCII is valid utf8
+                title: String::new(),
+                cgb: 0,
+                new_licensee_code: [0; 2],
+                sgb: 0,
+                cartridge_type: 0,
+                rom_size: 0,
+                ram_size: 0,
+                destination_code: 0,
+                old_licensee_code: 0,
+                mask_rom_version: 0,
+                header_checksum: 0,
+                global_checksum: 0,
+            },
+            data: [0; 0x10000],
+            boot_rom: None,
+            cgb: false,
+            model: Model::DMG,
+            requested_model: None,
+            compat_palette: None,
+            wram: [[0; 0x1000]; 8],
+            wram_bank: 1,
+            double_speed: false,
+            speed_switch_armed: false,
+            half_cycle: false,
+            scheduler: Scheduler::new(),
+            synced: 0,
+            hdma: Hdma::new(),
+            stall_cycles: 0,
+            timer: Timer::new(),
+            ppu: Ppu::new(),
+            joypad: Joypad::new(),
+            serial: Serial::new(),
+            apu: Apu::new(apu::DEFAULT_SAMPLE_RATE),
+            sgb: None,
+            cache: InstructionCache::new(CacheMode::RomAndRam),
+        }
+    }
+
+    /// Advance the CPU clock by `cycles` T-cycles
+    /// The components attached to memory are only run when one of their events is due, until then
+    /// they lag behind and are brought up to date by `sync`
+    pub fn advance(&mut self, cycles: u32) {
+        self.scheduler.advance(cycles);
+        while let Some((time, _)) = self.scheduler.pop_due() {
+            self.run_components(time);
+            self.schedule_events();
+        }
+        if self.joypad.take_interrupt() {
+            self.request_interrupt(Interrupt::Joypad);
+        }
+    }
+
+    /// T-cycles until the next scheduled event, before which no interrupt can be requested
+    /// except by the joypad
+    pub fn cycles_to_event(&self) -> Option<u32> {
+        self.scheduler.next().map(|time| {
+            time.saturating_sub(self.scheduler.now())
+                .min(u32::MAX as u64) as u32
+        })
+    }
+
+    /// T-cycles of the CPU since power on
+    pub fn cycles(&self) -> u64 {
+        self.scheduler.now()
+    }
+
+    /// Bring every component up to the current time
+    pub fn sync(&mut self) {
+        self.run_components(self.scheduler.now());
+    }
+
+    /// Run every component up to `time`
+    /// The frame sequencer of the APU is stepped on the falling edges of a bit of the timer counter
+    /// on the way
+    fn run_components(&mut self, time: u64) {
+        while self.synced < time {
+            let edge = self
+                .timer
+                .cycles_to_falling_edge(self.frame_sequencer_bit());
+            let cycles = (time - self.synced).min(edge as u64) as u32;
+            self.run_components_for(cycles);
+            if cycles == edge {
+                self.apu.step_frame_sequencer();
+            }
+        }
+    }
+
+    /// Run every component for `cycles` T-cycles
+    /// In double speed the timer and serial port follow the CPU, the other components run at
+    /// normal speed and only advance every other T-cycle
+    /// Interrupts raised by components are latched into IF
+    fn run_components_for(&mut self, cycles: u32) {
+        self.synced += cycles as u64;
+        self.timer.run(cycles);
+        if self.timer.take_interrupt() {
+            self.request_interrupt(Interrupt::Timer);
+        }
+        self.serial.run(cycles);
+        if self.serial.take_interrupt() {
+            self.request_interrupt(Interrupt::Serial);
+        }
+        let dots = if self.double_speed {
+            let cycles = cycles + self.half_cycle as u32;
+            self.half_cycle = cycles & 1 != 0;
+            cycles / 2
+        } else {
+            cycles
+        };
+        self.apu.run(dots);
+        self.ppu.run(dots);
+        if self.ppu.take_vblank_interrupt() {
+            self.request_interrupt(Interrupt::VBlank);
+            if let Some(sgb) = &mut self.sgb {
+                sgb.vblank(&self.ppu);
+            }
+        }
+        if self.ppu.take_stat_interrupt() {
+            self.request_interrupt(Interrupt::LCDStat);
+        }
+        if self.ppu.take_hblank_started() && self.hdma.hblank_active() {
+            self.hdma_copy_block();
+        }
+    }
+
+    /// Schedule the next event of every component, from the time they were run up to
+    /// Called after the components were run and after their registers were written
+    fn schedule_events(&mut self) {
+        let now = self.synced;
+        match self.timer.cycles_to_interrupt() {
+            Some(cycles) => self.scheduler.schedule(Event::Timer, now + cycles as u64),
+            None => self.scheduler.cancel(Event::Timer),
+        }
+        match self.serial.cycles_to_event() {
+            Some(cycles) => self.scheduler.schedule(Event::Serial, now + cycles as u64),
+            None => self.scheduler.cancel(Event::Serial),
+        }
+        match self.ppu.dots_to_event() {
+            Some(dots) => {
+                let cycles = if self.double_speed {
+                    dots * 2 - self.half_cycle as u32
+                } else {
+                    dots
+                };
+                self.scheduler.schedule(Event::Ppu, now + cycles as u64)
+            }
+            None => self.scheduler.cancel(Event::Ppu),
+        }
+        let cycles = self
+            .timer
+            .cycles_to_falling_edge(self.frame_sequencer_bit());
+        self.scheduler
+            .schedule(Event::FrameSequencer, now + cycles as u64);
+    }
+
+    /// Bit of the timer counter clocking the frame sequencer, bit 4 of DIV or bit 5 in double
+    /// speed
+    fn frame_sequencer_bit(&self) -> u16 {
+        1 << (12 + self.double_speed as u8)
+    }
+
+    /// Whether the cartridge runs in CGB mode
+    pub fn is_cgb(&self) -> bool {
+        self.cgb
+    }
+
+    /// Emulate a model instead of selecting it from the cartridge header
+    /// Must be set before loading the ROM
+    pub fn set_model(&mut self, model: Model) {
+        self.requested_model = Some(model);
+    }
+
+    /// Hardware model being emulated
+    pub fn model(&self) -> Model {
+        self.model
+    }
+
+    /// Palette of a DMG cartridge in compatibility mode, None outside of compatibility mode
+    pub fn compat_palette(&self) -> Option<CompatPalette> {
+        self.compat_palette
+    }
+
+    /// Override the palette of a DMG cartridge in compatibility mode
+    pub fn set_compat_palette(&mut self, palette: CompatPalette) {
+        if self.compat_palette.is_some() {
+            self.compat_palette = Some(palette);
+            self.update_dmg_colours();
+        }
+    }
+
+    /// Correct CGB colours, including those of the palette of a DMG cartridge in compatibility
+    /// mode, to look like they do on the LCD
+    pub fn set_colour_correction(&mut self, correct: bool) {
+        self.ppu.set_colour_correction(correct);
+        self.update_dmg_colours();
+    }
+
+    /// Display DMG shades with the compatibility palette, or in grey outside of compatibility mode
+    fn update_dmg_colours(&mut self) {
+        let colours = match self.compat_palette {
+            Some(palette) => palette.rgb888(self.ppu.colour_correction()),
+            None => [ppu::DMG_SHADES; 3],
+        };
+        self.ppu.set_dmg_colours(colours);
+    }
+
+    /// Whether the CPU runs at double speed
+    pub fn is_double_speed(&self) -> bool {
+        self.double_speed
+    }
+
+    /// Execute a speed switch if one was armed through KEY1
+    /// Called by the CPU on STOP, returns whether the speed was switched
+    /// Like on hardware, the switch resets DIV
+    pub fn speed_switch(&mut self) -> bool {
+        if !self.cgb || !self.speed_switch_armed {
+            return false;
+        }
+        self.sync();
+        self.speed_switch_armed = false;
+        self.double_speed = !self.double_speed;
+        self.half_cycle = false;
+        self.write_8(timer::DIV, 0);
+        true
+    }
+
+    /// Returns and clears the T-cycles the CPU has to stall for
+    pub fn take_stall_cycles(&mut self) -> u32 {
+        std::mem::take(&mut self.stall_cycles)
+    }
+
+    /// Copy the next VRAM DMA block, stalling the CPU while it is copied
+    fn hdma_copy_block(&mut self) {
+        let (source, dest) = self.hdma.next_block();
+        for i in 0..hdma::BLOCK_SIZE {
+            let byte = self.read_8(source.wrapping_add(i));
+            self.ppu.write_vram(dest + i, byte);
+        }
+        self.stall_cycles += hdma::BLOCK_CYCLES << self.double_speed as u8;
+    }
+
+    /// Super Game Boy, when the cartridge runs in SGB mode
+    pub fn sgb(&self) -> Option<&Sgb> {
+        self.sgb.as_ref()
+    }
+
+    /// Select the memory the decoded-instruction cache covers, RomAndRam by default
+    pub fn set_instruction_cache(&mut self, mode: CacheMode) {
+        self.cache = InstructionCache::new(mode);
+    }
+
+    /// ROM bank mapped at [0x4000..0x7FFF], always 1 without a memory bank controller
+    pub fn rom_bank(&self) -> usize {
+        1
+    }
+
+    /// Instruction decoded earlier at `addr`
+    pub fn cached_instruction(&self, addr: u16) -> Option<CachedInstruction> {
+        if self.boot_rom.is_some() && addr < 0x900 {
+            return None;
+        }
+        self.cache.get(addr, self.rom_bank(), self.wram_bank)
+    }
+
+    /// Cache the instruction fetched from `addr`
+    /// Nothing the boot ROM may be mapped over is cached, so unmapping it needs no invalidation
+    pub fn cache_instruction(&mut self, addr: u16, cached: CachedInstruction) {
+        if self.boot_rom.is_some() && addr < 0x900 {
+            return;
+        }
+        self.cache
+            .insert(addr, self.rom_bank(), self.wram_bank, cached);
+    }
+
+    /// Map a boot ROM to run at startup instead of starting in the post-boot state
+    /// Must be set before the ROM is loaded
+    pub fn set_boot_rom(&mut self, boot_rom: BootRom) {
+        self.boot_rom = Some(boot_rom);
+    }
+
+    /// Whether the boot ROM is still mapped
+    pub fn is_boot_rom_mapped(&self) -> bool {
+        self.boot_rom.is_some()
+    }
+
+    /// Access the timer
+    pub fn timer(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+
+    /// Access the joypad to press and release buttons
+    pub fn joypad(&mut self) -> &mut Joypad {
+        &mut self.joypad
+    }
+
+    /// Access the PPU to configure it and read the framebuffer
+    pub fn ppu(&mut self) -> &mut Ppu {
+        &mut self.ppu
+    }
+
+    /// RGB888 pixels of the picture, from the SGB when present or else from the PPU
+    pub fn framebuffer(&self) -> &[u8] {
+        match &self.sgb {
+            Some(sgb) => sgb.framebuffer(),
+            None => self.ppu.framebuffer(),
+        }
+    }
+
+    /// Access the APU to configure and take its audio output
+    pub fn apu(&mut self) -> &mut Apu {
+        &mut self.apu
+    }
+
+    /// Plug a link backend into the serial port
+    pub fn set_serial_link(&mut self, link: Box<dyn SerialLink>) {
+        self.serial.set_link(link);
+    }
+
+    /// Finish the output of the serial link, see `SerialLink::finish`
+    pub fn finish_serial_link(&mut self) -> io::Result<()> {
+        self.sync();
+        self.serial.finish_link()
+    }
+
+    /// Set the bit of an interrupt in IF
+    pub fn request_interrupt(&mut self, interrupt: Interrupt) {
+        self.data[IF as usize] |= 1 << interrupt as u8;
+    }
+}
+
+impl Default for Memory {
+    fn default() -> Memory {
+        Memory::new()
+    }
+}
+
+/// Whether an address is an I/O register, whose reads and writes depend on the components being
+/// up to date
+pub fn is_io(addr: u16) -> bool {
+    (0xFF00..=0xFF7F).contains(&addr)
+}
+
+/// Implement MemoryAccess for System
+impl RWAccess for Memory {
+    /// Read 8-bit value from memory
+    fn read_8(&self, addr: u16) -> u8 {
+        if let Some(val) = self
+            .boot_rom
+            .as_ref()
+            .and_then(|boot_rom| boot_rom.read(addr))
+        {
+            return val;
+        }
+        match addr {
+            joypad::P1 => self.joypad.read(),
+            serial::SB | serial::SC => self.serial.read(addr),
+            timer::DIV..=timer::TAC => self.timer.read(addr),
+            apu::NR10..=apu::APU_END => self.apu.read(addr),
+            IF => self.data[addr as usize] | 0xE0,
+            0x8000..=0x9FFF => self.ppu.read_vram(addr),
+            ppu::OAM..=ppu::OAM_END => self.ppu.read_oam(addr),
+            ppu::LCDC..=ppu::WX if addr != DMA => self.ppu.read(addr),
+            ppu::BCPS..=ppu::OCPD => self.ppu.read(addr),
+            0xC000..=0xCFFF => self.wram[0][addr as usize - 0xC000],
+            0xD000..=0xDFFF => self.wram[self.wram_bank][addr as usize - 0xD000],
+            // Echo RAM mirrors [0xC000..0xDDFF]
+            0xE000..=0xFDFF => self.read_8(addr - 0x2000),
+            KEY1 if self.cgb => {
+                0x7E | (self.double_speed as u8) << 7 | self.speed_switch_armed as u8
+            }
+            VBK if self.cgb => 0xFE | self.ppu.vram_bank() as u8,
+            hdma::HDMA1..=hdma::HDMA5 if self.cgb => self.hdma.read(addr),
+            SVBK if self.cgb => 0xF8 | self.wram_bank as u8,
+            KEY1 | VBK | SVBK | hdma::HDMA1..=hdma::HDMA5 => 0xFF,
+            _ => self.data[addr as usize],
+        }
+    }
+    /// Read 16-bit value from memory
+    fn read_16(&self, addr: u16) -> u16 {
+        self.read_8(addr) as u16 | (self.read_8(addr + 1) as u16) << 8
+    }
+    /// Write 8-bit value to memory
+    /// Writes to I/O registers bring the components up to date first, then latch the interrupts
+    /// they raise and reschedule their events
+    fn write_8(&mut self, addr: u16, value: u8) {
+        self.cache.invalidate(addr, self.wram_bank);
+        let io = is_io(addr);
+        if io {
+            self.sync();
+        }
+        match addr {
+            joypad::P1 => {
+                self.joypad.write(value);
+                if let Some(sgb) = &mut self.sgb {
+                    sgb.write_p1(value);
+                    if let Some(players) = sgb.take_players() {
+                        self.joypad.set_players(players);
+                    }
+                }
+            }
+            serial::SB | serial::SC => self.serial.write(addr, value),
+            timer::DIV => {
+                // Resetting the counter is a falling edge of the frame sequencer bit if it was set
+                if self.timer.counter() & self.frame_sequencer_bit() != 0 {
+                    self.apu.step_frame_sequencer();
+                }
+                self.timer.write(addr, value);
+            }
+            timer::TIMA..=timer::TAC => self.timer.write(addr, value),
+            apu::NR10..=apu::APU_END => self.apu.write(addr, value),
+            boot::BOOT if value != 0 => self.boot_rom = None,
+            // The cartridge ROM is read only, without a memory bank controller writes go nowhere
+            0x0000..=0x7FFF => {}
+            0x8000..=0x9FFF => self.ppu.write_vram(addr, value),
+            ppu::OAM..=ppu::OAM_END => self.ppu.write_oam(addr, value),
+            DMA => {
+                // The transfer is done at once instead of over 160 M-cycles
+                self.data[addr as usize] = value;
+                let source = (value as u16) << 8;
+                for i in 0..0xA0 {
+                    let byte = self.read_8(source + i);
+                    self.ppu.write_oam(ppu::OAM + i, byte);
+                }
+            }
+            ppu::LCDC..=ppu::WX => self.ppu.write(addr, value),
+            ppu::BCPS..=ppu::OCPD => self.ppu.write(addr, value),
+            0xC000..=0xCFFF => self.wram[0][addr as usize - 0xC000] = value,
+            0xD000..=0xDFFF => self.wram[self.wram_bank][addr as usize - 0xD000] = value,
+            0xE000..=0xFDFF => self.write_8(addr - 0x2000, value),
+            KEY1 if self.cgb => self.speed_switch_armed = value & 1 != 0,
+            VBK if self.cgb => self.ppu.set_vram_bank(value as usize),
+            hdma::HDMA1..=hdma::HDMA5 if self.cgb => {
+                if let Some(blocks) = self.hdma.write(addr, value) {
+                    for _ in 0..blocks {
+                        self.hdma_copy_block();
+                    }
+                }
+            }
+            SVBK if self.cgb => self.wram_bank = ((value & 0b111) as usize).max(1),
+            KEY1 | VBK | SVBK | hdma::HDMA1..=hdma::HDMA5 => {}
+            _ => self.data[addr as usize] = value,
+        }
+        if io {
+            // Interrupts raised by the write itself, such as STAT, are latched right away
+            self.run_components_for(0);
+            self.schedule_events();
+        }
+    }
+    /// Write 16-bit value to memory
+    fn write_16(&mut self, addr: u16, value: u16) {
+        self.write_8(addr, value as u8);
+        self.write_8(addr + 1, (value >> 8) as u8);
+    }
+}
+
+#[derive(Clone, Debug)]
+/// ROM Metadata
+/// This struct contains all the metadata of a ROM
+/// This is used to display information about the ROM
+/// and to check if the ROM is valid
+///
+/// # Fields
+/// * `title` - The title of the ROM [0x134..0x143] shares 0x143 with CGB Flag
+/// * `cgb` - CGB Flag [0x143]
+/// * `new_licensee_code` - New Licensee Code [0x144..0x145]
+/// * `sgb` - SGB Flag [0x146]
+/// * `cartridge_type` - Cartridge Type [0x147]
+/// * `rom_size` - ROM Size [0x148]
+/// * `ram_size` - RAM Size [0x149]
+/// * `destination_code` - Destination Code [0x14A]
+/// * `old_licensee_code` - Old Licensee Code [0x14B]
+/// * `mask_rom_version` - Mask ROM Version [0x14C]
+/// * `header_checksum` - Header Checksum [0x14D]
+/// * `global_checksum` - Global Checksum [0x14E..0x14F]
+pub struct ROMMetadata {
+    title: String,
+    cgb: u8,
+    new_licensee_code: [u8; 2],
+    sgb: u8,
+    cartridge_type: u8,
+    rom_size: u8,
+    ram_size: u8,
+    destination_code: u8,
+    old_licensee_code: u8,
+    mask_rom_version: u8,
+    header_checksum: u8,
+    global_checksum: u16,
+}
+
+impl ROMMetadata {
+    /// The 16 bytes of the title, including the byte shared with the CGB flag
+    pub fn title_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        let title = self.title.as_bytes();
+        bytes[..title.len()].copy_from_slice(title);
+        bytes[15] = self.cgb;
+        bytes
+    }
+
+    /// Sum of the title bytes, used by the CGB boot ROM to recognize cartridges
+    pub fn title_checksum(&self) -> u8 {
+        self.title_bytes()
+            .iter()
+            .fold(0, |sum, byte| sum.wrapping_add(*byte))
+    }
+
+    /// Whether the CGB flag marks the cartridge as CGB enhanced (0x80) or CGB only (0xC0)
+    pub fn supports_cgb(&self) -> bool {
+        self.cgb & 0x80 != 0
+    }
+
+    /// Cartridge type [0x147], the memory bank controller and other hardware on the cartridge
+    pub fn cartridge_type(&self) -> u8 {
+        self.cartridge_type
+    }
+
+    /// ROM size [0x148], 32 KiB shifted left by this value
+    pub fn rom_size(&self) -> u8 {
+        self.rom_size
+    }
+
+    /// RAM size [0x149]
+    pub fn ram_size(&self) -> u8 {
+        self.ram_size
+    }
+
+    /// Destination code [0x14A], 0x00 for Japan and 0x01 for everywhere else
+    pub fn destination_code(&self) -> u8 {
+        self.destination_code
+    }
+
+    /// Mask ROM version [0x14C]
+    pub fn mask_rom_version(&self) -> u8 {
+        self.mask_rom_version
+    }
+
+    /// Header checksum [0x14D]
+    pub fn header_checksum(&self) -> u8 {
+        self.header_checksum
+    }
+
+    /// Whether the cartridge supports SGB functions
+    pub fn is_sgb(&self) -> bool {
+        self.sgb == 0x03 && self.old_licensee_code == 0x33
+    }
+
+    /// Whether the licensee is Nintendo, old licensee 0x01 or new licensee "01"
+    pub fn is_nintendo(&self) -> bool {
+        self.old_licensee_code == 0x01
+            || (self.old_licensee_code == 0x33 && &self.new_licensee_code == b"01")
+    }
+}
+
+/// ROMAccess trait for ROM
+/// This trait is used to access ROMs
+pub trait ROMAccess {
+    fn load_rom(&mut self, location: &str) -> io::Result<()>;
+    fn load_rom_data(&mut self, data: &[u8]) -> io::Result<()>;
+    fn get_metadata(&mut self) -> ROMMetadata;
+    fn decode(&mut self, addr: u16) -> (u16, Instruction);
+}
+
+/// Implement ROMAccess for ROM
+impl ROMAccess for Memory {
+    /// Load a ROM from a file
+    fn load_rom(&mut self, location: &str) -> io::Result<()> {
+        self.load_rom_data(&fs::read(location)?)
+    }
+
+    /// Load a ROM from its bytes
+    /// Loads metadata & data
+    /// Will also check if header checksum is correct
+    /// Global checksum is not checked
+    /// Documented at: https://gbdev.gg8.se/wiki/articles/The_Cartridge_Header#014D_-_Header_Checksum
+    fn load_rom_data(&mut self, data: &[u8]) -> io::Result<()> {
+        let invalid = |problem: &str| io::Error::new(io::ErrorKind::InvalidData, problem);
+        if data.len() < 0x150 {
+            return Err(invalid("Invalid ROM: Smaller than the header"));
+        }
+        self.rom_metadata = ROMMetadata {
+            // ASCII is valid utf8
+            title: String::from_utf8(data[0x134..0x143].to_vec())
+                .map_err(|_| invalid("Invalid ROM: Could not read title"))?,
+            cgb: data[0x143],
+            new_licensee_code: [data[0x144], data[0x145]],
+            sgb: data[0x146],
+            cartridge_type: data[0x147],
+            rom_size: data[0x148],
+            ram_size: data[0x149],
+            destination_code: data[0x14A],
+            old_licensee_code: data[0x14B],
+            mask_rom_version: data[0x14C],
+            header_checksum: data[0x14D],
+            global_checksum: u16::from_le_bytes([data[0x14E], data[0x14F]]),
+        };
+        let mut checksum: u8 = 0;
+        for byte in &data[0x134..0x14D] {
+            checksum = checksum.wrapping_sub(*byte).wrapping_sub(1);
+        }
+        if checksum != self.rom_metadata.header_checksum {
+            return Err(invalid("Invalid ROM: Checksum failed"));
+        }
+        self.model = self
+            .requested_model
+            .unwrap_or_else(|| Model::detect(&self.rom_metadata));
+        // CGB flag 0x80 (CGB enhanced) or 0xC0 (CGB only) start the CGB in CGB mode
+        self.cgb = self.model.is_cgb() && self.rom_metadata.supports_cgb();
+        self.serial.set_cgb(self.cgb);
+        self.apu.set_model(self.model);
+        self.ppu.set_model(self.model);
+        self.ppu.set_cgb(self.cgb);
+        // A DMG cartridge on a CGB is colourized by the boot ROM, which picks a palette from the
+        // header or from the buttons held while the logo is shown
+        self.compat_palette = None;
+        if self.model.is_cgb() && !self.cgb {
+            let joypad = &self.joypad;
+            let palette =
+                CompatPalette::boot(&self.rom_metadata, &|button| joypad.is_pressed(button));
+            self.compat_palette = Some(palette);
+        }
+        self.update_dmg_colours();
+        // SGB functions are only enabled by an SGB flag of 0x03 and an old licensee code of 0x33
+        self.sgb = None;
+        if self.model.is_sgb() && self.rom_metadata.is_sgb() {
+            self.sgb = Some(Sgb::new());
+        }
+        self.cache.clear();
+        // Without a memory bank controller only the first 32 KiB are mapped, a smaller ROM leaves
+        // the rest of the range unconnected
+        let len = data.len().min(0x8000);
+        self.data[..len].copy_from_slice(&data[..len]);
+        self.data[len..0x8000].fill(0xFF);
+        // Without a boot ROM start in the state it would leave behind
+        if self.boot_rom.is_none() {
+            boot::skip(self);
+        }
+        self.schedule_events();
+        Ok(())
+    }
+
+    /// Get Metadata from ROM
+    fn get_metadata(&mut self) -> ROMMetadata {
+        self.rom_metadata.clone()
+    }
+
+    /// Decodes the instruction at address and returns end of address and the decoded instruction
+    /// If used for linear progression through a program, it is expected that the caller function updates it's own address counter with the one outputted by this function
+    /// Example for reading the instructions between 180 and 190:
+    /// ```ignore
+    /// let mut addr = 0x180;
+    /// while addr < 0x190 {
+    ///    let (n_addr, instruction) = rom.decode(addr);
+    ///    println!("{:x}\t {}", addr, instruction);
+    ///    addr = n_addr;
+    /// }
+    /// ```
+    fn decode(&mut self, addr: u16) -> (u16, Instruction) {
+        let opcode = self.read_8(addr);
+        // The length comes from the table, the byte after STOP is not read as an operand
+        let bytes = match opcode {
+            0xCB => cb_prefixed_opcode_get(self.read_8(addr.wrapping_add(1))).bytes,
+            _ => unprefixed_opcode_get(opcode).bytes,
+        };
+        let mut next = addr.wrapping_add(1);
+        let instruction = Instruction::unprefixed(opcode, &mut || {
+            let val = self.read_8(next);
+            next = next.wrapping_add(1);
+            val
+        });
+        (addr.wrapping_add(bytes as u16), instruction) // Address should be updated in caller to prevent double execution of instructions
+    }
+}
+
+/// The cartridge ROM [0x0000..0x7FFF] is not part of the state, a state only loads over the
+/// cartridge it was saved with. The components are expected to be synced when saving.
+impl Savestate for Memory {
+    fn state(&mut self, state: &mut State) {
+        let mut checksum = self.rom_metadata.global_checksum;
+        state.u16(&mut checksum);
+        state.check(
+            checksum == self.rom_metadata.global_checksum,
+            "save state is of another cartridge",
+        );
+        state.bytes(&mut self.data[0x8000..]);
+        if state.present(self.boot_rom.is_some()) {
+            self.boot_rom
+                .get_or_insert_with(BootRom::default)
+                .state(state);
+        } else {
+            self.boot_rom = None;
+        }
+        state.bool(&mut self.cgb);
+        self.model.state(state);
+        if state.present(self.compat_palette.is_some()) {
+            let mut palette = self.compat_palette.unwrap_or_default();
+            palette.state(state);
+            self.compat_palette = Some(palette);
+        } else {
+            self.compat_palette = None;
+        }
+        for bank in &mut self.wram {
+            state.bytes(bank);
+        }
+        state.usize(&mut self.wram_bank);
+        state.check(
+            (1..self.wram.len()).contains(&self.wram_bank),
+            "WRAM bank out of range",
+        );
+        state.bool(&mut self.double_speed);
+        state.bool(&mut self.speed_switch_armed);
+        state.bool(&mut self.half_cycle);
+        let mut now = self.scheduler.now();
+        state.u64(&mut now);
+        self.hdma.state(state);
+        state.u32(&mut self.stall_cycles);
+        self.timer.state(state);
+        self.ppu.state(state);
+        self.joypad.state(state);
+        self.serial.state(state);
+        self.apu.state(state);
+        if state.present(self.sgb.is_some()) {
+            self.sgb.get_or_insert_with(Sgb::new).state(state);
+        } else {
+            self.sgb = None;
+        }
+        if state.is_loading() && !state.failed() {
+            self.cache.clear();
+            self.scheduler.reset(now);
+            self.synced = now;
+            self.apu.set_model(self.model);
+            self.update_dmg_colours();
+            self.schedule_events();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_rom::{cgb_rom, rom};
+    use super::super::System;
+    use super::*;
+
+    /// Memory running `rom`
+    fn memory(rom: &[u8]) -> Memory {
+        let mut memory = Memory::new();
+        memory.load_rom_data(rom).unwrap();
+        memory
+    }
+
+    /// T-cycles until `interrupt` is requested, with `writes` of (cycle, address, value) done on the
+    /// way. Unless `sync`, the components only run when their scheduled event is due.
+    fn interrupt_cycle(sync: bool, writes: &[(u64, u16, u8)], interrupt: Interrupt) -> u64 {
+        let mut memory = memory(&rom(&[]));
+        let start = memory.cycles();
+        while memory.cycles() - start < 200_000 {
+            let now = memory.cycles() - start;
+            for (_, addr, val) in writes.iter().filter(|(at, _, _)| *at == now) {
+                memory.write_8(*addr, *val);
+            }
+            memory.advance(4);
+            if sync {
+                memory.sync();
+            }
+            if memory.read_8(IF) & 1 << interrupt as u8 != 0 {
+                return memory.cycles() - start;
+            }
+        }
+        panic!("ERROR: {:?} interrupt not requested", interrupt);
+    }
+
+    /// The interrupt is requested at the same cycle as when every component runs all the time
+    fn assert_rescheduled(writes: &[(u64, u16, u8)], interrupt: Interrupt) -> u64 {
+        let cycle = interrupt_cycle(true, writes, interrupt);
+        assert_eq!(interrupt_cycle(false, writes, interrupt), cycle);
+        cycle
+    }
+
+    #[test]
+    fn tima_write_reschedules_the_overflow() {
+        // With the screen off, only the frame sequencer runs the components in between
+        let writes = [
+            (0, ppu::LCDC, 0x11),
+            (0, timer::TAC, 0b100),
+            (0, timer::TIMA, 0x00),
+            (0, IF, 0x00),
+            (2000, timer::TIMA, 0xFF),
+        ];
+        let cycle = assert_rescheduled(&writes, Interrupt::Timer);
+        assert!((2000..2000 + 1024 + 8).contains(&cycle));
+    }
+
+    #[test]
+    fn tac_write_reschedules_the_overflow() {
+        let writes = [
+            (0, ppu::LCDC, 0x11),
+            (0, timer::TAC, 0b100),
+            (0, timer::TIMA, 0xF0),
+            (0, IF, 0x00),
+            (2000, timer::TAC, 0b101),
+        ];
+        let cycle = assert_rescheduled(&writes, Interrupt::Timer);
+        assert!((2000..2000 + 16 * 16 + 8).contains(&cycle));
+    }
+
+    #[test]
+    fn lcdc_write_reschedules_the_ppu() {
+        // HBlank STAT interrupt of the first line after the screen is turned on
+        let writes = [
+            (0, ppu::LCDC, 0x11),
+            (0, ppu::STAT, 0x08),
+            (0, IF, 0x00),
+            (3000, ppu::LCDC, 0x91),
+        ];
+        let cycle = assert_rescheduled(&writes, Interrupt::LCDStat);
+        assert!((3000..3000 + 456).contains(&cycle));
+    }
+
+    #[test]
+    fn stop_switches_speed_when_armed() {
+        let mut system = System::new(memory(&cgb_rom(&[
+            0x3E, 0x01, // ld a, 1
+            0xE0, 0x4D, // ldh [KEY1], a
+            0x10, 0x00, // stop
+            0x18, 0xFE, // jr @
+        ])));
+        assert_eq!(system.memory().read_8(KEY1), 0x7E);
+        system.run_until(|system| system.cpu.pc() == 0x154);
+        assert_eq!(system.memory().read_8(KEY1), 0x7F);
+        assert!(!system.memory().is_double_speed());
+        system.run_until(|system| system.cpu.pc() == 0x156);
+        let memory = system.memory();
+        assert!(memory.is_double_speed());
+        assert_eq!(memory.read_8(KEY1), 0xFE);
+
+        // Without the CGB mode KEY1 is not there
+        let mut memory = self::memory(&rom(&[]));
+        memory.write_8(KEY1, 0x01);
+        assert_eq!(memory.read_8(KEY1), 0xFF);
+        assert!(!memory.speed_switch());
+    }
+
+    #[test]
+    fn svbk_selects_wram_banks() {
+        let mut memory = memory(&cgb_rom(&[]));
+        for bank in 1..8 {
+            memory.write_8(SVBK, bank);
+            memory.write_8(0xD000, bank * 0x11);
+        }
+        for bank in 1..8 {
+            memory.write_8(SVBK, bank);
+            assert_eq!(memory.read_8(SVBK), 0xF8 | bank);
+            assert_eq!(memory.read_8(0xD000), bank * 0x11);
+        }
+        // Bank 0 selects bank 1, bank 0 is always at 0xC000
+        memory.write_8(SVBK, 0);
+        assert_eq!(memory.read_8(SVBK), 0xF9);
+        assert_eq!(memory.read_8(0xD000), 0x11);
+        memory.write_8(0xC000, 0x42);
+        memory.write_8(SVBK, 3);
+        assert_eq!(memory.read_8(0xC000), 0x42);
+        // Echo RAM follows the selected bank
+        assert_eq!(memory.read_8(0xF000), 0x33);
+    }
+
+    #[test]
+    fn vbk_switches_vram_banks_in_cgb_mode_only() {
+        let mut memory = memory(&cgb_rom(&[]));
+        memory.write_8(0x8000, 0x12);
+        memory.write_8(VBK, 1);
+        assert_eq!(memory.read_8(VBK), 0xFF);
+        assert_eq!(memory.read_8(0x8000), 0x00);
+        memory.write_8(0x8000, 0x34);
+        memory.write_8(VBK, 0);
+        assert_eq!(memory.read_8(VBK), 0xFE);
+        assert_eq!(memory.read_8(0x8000), 0x12);
+
+        let mut memory = self::memory(&rom(&[]));
+        memory.write_8(0x8000, 0x12);
+        memory.write_8(VBK, 1);
+        assert_eq!(memory.read_8(VBK), 0xFF);
+        assert_eq!(memory.read_8(0x8000), 0x12);
+        memory.write_8(0x8000, 0x34);
+        memory.write_8(VBK, 0);
+        assert_eq!(memory.read_8(0x8000), 0x34);
+    }
+}
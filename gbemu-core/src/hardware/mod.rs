@@ -0,0 +1,398 @@
+pub mod apu;
+pub mod boot;
+pub mod bus;
+pub mod cache;
+pub mod compat;
+pub mod cpu;
+pub mod hdma;
+pub mod interrupt;
+pub mod joypad;
+pub mod mem;
+pub mod model;
+pub mod pacer;
+pub mod ppu;
+pub mod rw;
+pub mod scheduler;
+pub mod serial;
+pub mod sgb;
+pub mod state;
+#[cfg(test)]
+pub mod test_rom;
+pub mod timer;
+
+use apu::resampler::DynamicRateControl;
+use cpu::Execution;
+use joypad::Button;
+use mem::Memory;
+use pacer::Pacer;
+use state::{Savestate, State};
+use std::collections::HashSet;
+use std::io;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Why a run of the system stopped
+pub enum StopReason {
+    /// The PPU completed a frame, or the time of a frame passed with the LCD off
+    FrameComplete,
+    /// PC reached a breakpoint, the instruction there is executed when running again
+    Breakpoint(u16),
+    /// The CPU locked up on an illegal opcode at this address
+    IllegalOpcode(u16),
+    /// The T-cycles of `run_cycles` or the instruction of `step_instruction` were run
+    CycleBudget,
+    /// The predicate of `run_until` held
+    Predicate,
+}
+
+/// System struct
+/// Owns the memory, which is lent to the CPU as its bus for every step
+pub struct System {
+    /// CPU
+    cpu: cpu::CPU,
+
+    /// Memory
+    memory: Memory,
+
+    /// Addresses at which running stops before executing the instruction
+    breakpoints: HashSet<u16>,
+
+    /// Adjusts the audio output rate to the queue of the frontend, if enabled
+    rate_control: Option<DynamicRateControl>,
+}
+
+impl System {
+    /// Create a new System from memory with the ROM loaded
+    pub fn new(mut memory: Memory) -> System {
+        System {
+            cpu: cpu::CPU::new(&mut memory),
+            memory,
+            breakpoints: HashSet::new(),
+            rate_control: None,
+        }
+    }
+
+    /// Access the memory and the components attached to it
+    pub fn memory(&mut self) -> &mut Memory {
+        &mut self.memory
+    }
+
+    /// RGB888 pixels of the screen, row by row
+    /// In SGB mode this is the picture including the border
+    pub fn framebuffer(&self) -> &[u8] {
+        self.memory.framebuffer()
+    }
+
+    /// Hardware model being emulated
+    pub fn model(&self) -> model::Model {
+        self.memory.model()
+    }
+
+    /// Width and height of the framebuffer
+    pub fn screen_size(&self) -> (usize, usize) {
+        match self.memory.sgb() {
+            Some(_) => (sgb::SGB_WIDTH, sgb::SGB_HEIGHT),
+            None => (ppu::SCREEN_WIDTH, ppu::SCREEN_HEIGHT),
+        }
+    }
+
+    /// Set the audio output rate in Hz, `apu::DEFAULT_SAMPLE_RATE` until set
+    /// Samples not read yet are dropped
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.memory.sync();
+        self.memory.apu().set_sample_rate(sample_rate);
+    }
+
+    /// Keep the audio queue of the frontend near `target` stereo samples by slightly adjusting the
+    /// output rate, so audio neither underruns nor lags when the emulator and the audio device
+    /// run at slightly different speeds. None outputs exactly at the sample rate.
+    pub fn set_audio_target(&mut self, target: Option<usize>) {
+        self.rate_control = target.map(DynamicRateControl::new);
+        if self.rate_control.is_none() {
+            self.memory.apu().set_rate_adjust(1.0);
+        }
+    }
+
+    /// Read interleaved left and right audio samples into `buf`
+    /// `queued` is the number of stereo samples the frontend still has queued for the audio
+    /// device, which steers the output rate when an audio target is set
+    /// Returns the number of stereo samples read
+    pub fn read_audio(&mut self, buf: &mut [f32], queued: usize) -> usize {
+        self.memory.sync();
+        let apu = self.memory.apu();
+        let count = apu.read_samples(buf);
+        if let Some(rate_control) = &self.rate_control {
+            apu.set_rate_adjust(rate_control.adjust(queued + count));
+        }
+        count
+    }
+
+    /// Press a button of the first player
+    pub fn press(&mut self, button: Button) {
+        self.memory.joypad().press(button);
+    }
+
+    /// Release a button of the first player
+    pub fn release(&mut self, button: Button) {
+        self.memory.joypad().release(button);
+    }
+
+    /// Save the state of the system
+    /// The cartridge, the attached serial link and the configuration of the frontend are not part
+    /// of the state
+    pub fn save_state(&mut self) -> Vec<u8> {
+        self.memory.sync();
+        let mut state = State::saver();
+        self.state(&mut state);
+        state.finish().expect("ERROR: Could not save state")
+    }
+
+    /// Load a state saved by `save_state` with the same cartridge loaded
+    /// The system is left unchanged if the state is invalid
+    pub fn load_state(&mut self, data: &[u8]) -> io::Result<()> {
+        let backup = self.save_state();
+        let mut state = State::loader(data);
+        self.state(&mut state);
+        if let Err(e) = state.finish() {
+            let mut state = State::loader(&backup);
+            self.state(&mut state);
+            state.finish().expect("ERROR: Could not restore state");
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// T-cycles of the CPU since power on
+    pub fn cycles(&self) -> u64 {
+        self.memory.cycles()
+    }
+
+    /// Stop running before the instruction at `addr` is executed
+    pub fn add_breakpoint(&mut self, addr: u16) {
+        self.breakpoints.insert(addr);
+    }
+
+    /// Remove the breakpoint at `addr`
+    pub fn remove_breakpoint(&mut self, addr: u16) {
+        self.breakpoints.remove(&addr);
+    }
+
+    /// Run until the PPU completes a frame
+    /// With the LCD off this runs for the time of a frame instead
+    pub fn run_frame(&mut self) -> StopReason {
+        let cycles = (ppu::FRAME_DOTS as u64) << self.memory.is_double_speed() as u8;
+        match self.run(Some(cycles), true, |_| false) {
+            StopReason::CycleBudget => StopReason::FrameComplete,
+            reason => reason,
+        }
+    }
+
+    /// Run for at least `cycles` T-cycles, the last instruction is completed
+    pub fn run_cycles(&mut self, cycles: u64) -> StopReason {
+        self.run(Some(cycles), false, |_| false)
+    }
+
+    /// Run until `predicate` holds after an instruction
+    pub fn run_until(&mut self, predicate: impl FnMut(&System) -> bool) -> StopReason {
+        self.run(None, false, predicate)
+    }
+
+    /// Execute a single instruction, or dispatch an interrupt or idle while halted
+    pub fn step_instruction(&mut self) -> StopReason {
+        self.run(Some(1), true, |_| false)
+    }
+
+    /// Step the CPU until a reason to stop
+    /// The breakpoint at the starting address is ignored, so running again after a breakpoint
+    /// continues past it. Illegal opcodes only stop running when the CPU locks up, a CPU that is
+    /// already locked keeps idling while the rest of the system runs.
+    fn run(
+        &mut self,
+        budget: Option<u64>,
+        frame: bool,
+        mut predicate: impl FnMut(&System) -> bool,
+    ) -> StopReason {
+        let start = self.cycles();
+        let locked = self.cpu.is_locked();
+        let mut first = true;
+        loop {
+            let pc = self.cpu.pc();
+            if !first && !self.cpu.is_halted() && self.breakpoints.contains(&pc) {
+                return StopReason::Breakpoint(pc);
+            }
+            first = false;
+            self.cpu.step(&mut self.memory);
+            if let Some(addr) = self.cpu.locked_at().filter(|_| !locked) {
+                return StopReason::IllegalOpcode(addr);
+            }
+            // Taken on every step so a frame completed by another kind of run is not reported
+            if self.memory.ppu().take_frame_complete() && frame {
+                return StopReason::FrameComplete;
+            }
+            if predicate(self) {
+                return StopReason::Predicate;
+            }
+            if budget.is_some_and(|budget| self.cycles() - start >= budget) {
+                return StopReason::CycleBudget;
+            }
+        }
+    }
+
+    /// Run in real time until the CPU locks up
+    pub fn run_realtime(&mut self) -> StopReason {
+        let mut pacer = Pacer::new();
+        loop {
+            match self.run_frame() {
+                StopReason::FrameComplete => pacer.wait(),
+                reason => return reason,
+            }
+        }
+    }
+}
+
+impl Savestate for System {
+    fn state(&mut self, state: &mut State) {
+        let mut magic = state::MAGIC;
+        let mut version = state::VERSION;
+        state.bytes(&mut magic);
+        state.u32(&mut version);
+        state.check(magic == state::MAGIC, "not a save state");
+        state.check(
+            version == state::VERSION,
+            "save state version is not supported",
+        );
+        // The layout of anything else is unknown
+        if state.failed() {
+            return;
+        }
+        self.cpu.state(state);
+        self.memory.state(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mem::ROMAccess;
+    use super::rw::RWAccess;
+    use super::*;
+
+    #[test]
+    fn save_state_round_trips() {
+        let mut system = test_rom::system(&[0x18, 0xFE]);
+        system.run_frame();
+        let saved = system.save_state();
+        system.run_frame();
+        system.load_state(&saved).unwrap();
+        assert_eq!(system.save_state(), saved);
+    }
+
+    #[test]
+    fn whole_rom_is_mapped() {
+        let mut rom = test_rom::rom(&[0x18, 0xFE]);
+        rom[0x3FFF] = 0x24;
+        rom[0x7FFF] = 0x42;
+        let mut memory = Memory::new();
+        memory.load_rom_data(&rom).unwrap();
+        assert_eq!(memory.read_8(0x3FFF), 0x24);
+        assert_eq!(memory.read_8(0x7FFF), 0x42);
+
+        // Only the header is required
+        memory.load_rom_data(&rom[..0x150]).unwrap();
+        assert_eq!(memory.read_8(0x014F), rom[0x014F]);
+        assert_eq!(memory.read_8(0x7FFF), 0xFF);
+        assert!(memory.load_rom_data(&rom[..0x14F]).is_err());
+    }
+
+    #[test]
+    fn rom_is_read_only() {
+        let mut system = test_rom::system(&[0x18, 0xFE]);
+        let memory = system.memory();
+        memory.write_8(test_rom::PROGRAM as u16, 0x00);
+        assert_eq!(memory.read_8(test_rom::PROGRAM as u16), 0x18);
+    }
+
+    #[test]
+    fn breakpoint_is_passed_when_running_again() {
+        let mut system = test_rom::system(&[
+            0x00, // nop
+            0x3C, // .loop: inc a
+            0x18, 0xFD, // jr .loop
+        ]);
+        system.add_breakpoint(0x151);
+        assert_eq!(system.run_frame(), StopReason::Breakpoint(0x151));
+        let cycles = system.cycles();
+        assert_eq!(system.run_frame(), StopReason::Breakpoint(0x151));
+        assert_eq!(system.cycles() - cycles, 4 + 12);
+        system.remove_breakpoint(0x151);
+        assert_eq!(system.run_frame(), StopReason::FrameComplete);
+    }
+
+    #[test]
+    fn illegal_opcode_stops_once() {
+        let mut system = test_rom::system(&[0x00, 0xD3]);
+        assert_eq!(system.run_frame(), StopReason::IllegalOpcode(0x151));
+        // The locked CPU idles while the rest of the system runs
+        assert_eq!(system.run_frame(), StopReason::FrameComplete);
+    }
+
+    #[test]
+    fn run_until_stops_after_the_instruction() {
+        let mut system = test_rom::system(&[
+            0x21, 0x00, 0xC0, // ld hl, $C000
+            0x34, // .loop: inc [hl]
+            0x18, 0xFD, // jr .loop
+        ]);
+        system.memory().write_8(0xC000, 0);
+        let reason = system.run_until(|system| system.memory.read_8(0xC000) == 5);
+        assert_eq!(reason, StopReason::Predicate);
+        assert_eq!(system.memory().read_8(0xC000), 5);
+        assert_eq!(system.cpu.pc(), 0x154);
+    }
+
+    #[test]
+    fn step_instruction_runs_one_instruction() {
+        let mut system = test_rom::system(&[
+            0x21, 0x00, 0xC0, // ld hl, $C000
+            0x00, // nop
+        ]);
+        // nop; jp $0150 of the entry point
+        system.step_instruction();
+        system.step_instruction();
+        assert_eq!(system.cpu.pc(), 0x150);
+        let cycles = system.cycles();
+        assert_eq!(system.step_instruction(), StopReason::CycleBudget);
+        assert_eq!(system.cpu.pc(), 0x153);
+        assert_eq!(system.cycles() - cycles, 12);
+        assert_eq!(system.step_instruction(), StopReason::CycleBudget);
+        assert_eq!(system.cpu.pc(), 0x154);
+        assert_eq!(system.cycles() - cycles, 16);
+    }
+
+    /// Run a frame and read its audio, with `queued` samples left in the queue of the frontend
+    fn frame_samples(system: &mut System, queued: usize) -> usize {
+        system.run_frame();
+        let mut buf = [0.0; 4096];
+        system.read_audio(&mut buf, queued)
+    }
+
+    #[test]
+    fn audio_rate_follows_the_queue() {
+        let mut system = test_rom::system(&[0x18, 0xFE]);
+        system.set_sample_rate(48000);
+        frame_samples(&mut system, 0);
+        // 48000 Hz at 59.7 frames per second
+        let nominal = frame_samples(&mut system, 0);
+        assert!((803..=804).contains(&nominal), "{}", nominal);
+
+        system.set_audio_target(Some(4800));
+        frame_samples(&mut system, 0);
+        let starved = frame_samples(&mut system, 0);
+        frame_samples(&mut system, 20000);
+        let flooded = frame_samples(&mut system, 20000);
+        assert!(starved > nominal, "{} {}", starved, nominal);
+        assert!(flooded < nominal, "{} {}", flooded, nominal);
+
+        system.set_audio_target(None);
+        frame_samples(&mut system, 20000);
+        assert!((803..=804).contains(&frame_samples(&mut system, 20000)));
+    }
+}
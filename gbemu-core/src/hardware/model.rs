@@ -0,0 +1,160 @@
+use super::mem::ROMMetadata;
+use super::state::{Savestate, State};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Game Boy hardware revisions
+/// They differ in the state left by the boot ROM, in hardware quirks and in which features exist
+pub enum Model {
+    /// Original Game Boy with the early boot ROM
+    DMG0,
+    /// Original Game Boy
+    DMG,
+    /// Game Boy Pocket and Game Boy Light
+    MGB,
+    /// Super Game Boy
+    SGB,
+    /// Super Game Boy 2
+    SGB2,
+    /// Early Game Boy Color
+    CGB0,
+    /// Game Boy Color
+    CGB,
+    /// Game Boy Advance running Game Boy cartridges
+    AGB,
+}
+
+impl Model {
+    /// Pick the model a cartridge is best played on, a CGB for CGB cartridges, an SGB for SGB
+    /// cartridges and a DMG for all others
+    pub fn detect(metadata: &ROMMetadata) -> Model {
+        if metadata.supports_cgb() {
+            Model::CGB
+        } else if metadata.is_sgb() {
+            Model::SGB
+        } else {
+            Model::DMG
+        }
+    }
+
+    /// Parse a model from its name, e.g. "cgb"
+    pub fn parse(name: &str) -> Option<Model> {
+        Some(match name.to_ascii_uppercase().as_str() {
+            "DMG0" => Model::DMG0,
+            "DMG" => Model::DMG,
+            "MGB" => Model::MGB,
+            "SGB" => Model::SGB,
+            "SGB2" => Model::SGB2,
+            "CGB0" => Model::CGB0,
+            "CGB" => Model::CGB,
+            "AGB" => Model::AGB,
+            _ => return None,
+        })
+    }
+
+    /// Whether the model has the CGB hardware, which runs DMG cartridges in compatibility mode
+    pub fn is_cgb(self) -> bool {
+        matches!(self, Model::CGB0 | Model::CGB | Model::AGB)
+    }
+
+    /// Whether the model is a Super Game Boy
+    pub fn is_sgb(self) -> bool {
+        matches!(self, Model::SGB | Model::SGB2)
+    }
+}
+
+impl Savestate for Model {
+    fn state(&mut self, state: &mut State) {
+        let models = [
+            Model::DMG0,
+            Model::DMG,
+            Model::MGB,
+            Model::SGB,
+            Model::SGB2,
+            Model::CGB0,
+            Model::CGB,
+            Model::AGB,
+        ];
+        let mut index = *self as u8;
+        state.u8(&mut index);
+        state.check((index as usize) < models.len(), "unknown model");
+        *self = models[index as usize % models.len()];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mem::{Memory, ROMAccess};
+    use super::super::test_rom::{set_header, titled_rom};
+    use super::*;
+
+    /// Memory with a ROM of the CGB flag, SGB flag and old licensee code, emulating `requested`
+    fn load(cgb: u8, sgb: u8, licensee: u8, requested: Option<Model>) -> Memory {
+        let mut rom = titled_rom(b"TEST", licensee, &[]);
+        set_header(&mut rom, 0x143, cgb);
+        set_header(&mut rom, 0x146, sgb);
+        let mut memory = Memory::new();
+        if let Some(model) = requested {
+            memory.set_model(model);
+        }
+        memory.load_rom_data(&rom).unwrap();
+        memory
+    }
+
+    #[test]
+    fn detect_from_the_header() {
+        let cases = [
+            (0x00, 0x00, 0x00, Model::DMG),
+            (0x80, 0x00, 0x00, Model::CGB),
+            (0xC0, 0x00, 0x00, Model::CGB),
+            (0x00, 0x03, 0x33, Model::SGB),
+            // The SGB flag needs the old licensee code 0x33
+            (0x00, 0x03, 0x01, Model::DMG),
+            (0x00, 0x00, 0x33, Model::DMG),
+            (0x80, 0x03, 0x33, Model::CGB),
+        ];
+        for (cgb, sgb, licensee, expected) in cases {
+            let mut memory = load(cgb, sgb, licensee, None);
+            assert_eq!(Model::detect(&memory.get_metadata()), expected);
+            assert_eq!(memory.model(), expected);
+        }
+    }
+
+    #[test]
+    fn requested_model_overrides_detection() {
+        // A CGB cartridge on a DMG runs in DMG mode
+        let memory = load(0x80, 0x00, 0x00, Some(Model::DMG));
+        assert_eq!(memory.model(), Model::DMG);
+        assert!(!memory.is_cgb());
+        // A DMG cartridge on a CGB runs in compatibility mode
+        let memory = load(0x00, 0x00, 0x00, Some(Model::CGB));
+        assert_eq!(memory.model(), Model::CGB);
+        assert!(!memory.is_cgb());
+        assert!(memory.compat_palette().is_some());
+        // SGB functions need an SGB cartridge
+        let memory = load(0x00, 0x00, 0x00, Some(Model::SGB));
+        assert_eq!(memory.model(), Model::SGB);
+        assert!(memory.sgb().is_none());
+        let memory = load(0x00, 0x03, 0x33, Some(Model::SGB2));
+        assert_eq!(memory.model(), Model::SGB2);
+        assert!(memory.sgb().is_some());
+    }
+
+    #[test]
+    fn parse_names() {
+        let cases = [
+            ("dmg0", Some(Model::DMG0)),
+            ("DMG", Some(Model::DMG)),
+            ("mgb", Some(Model::MGB)),
+            ("Sgb", Some(Model::SGB)),
+            ("sgb2", Some(Model::SGB2)),
+            ("cgb0", Some(Model::CGB0)),
+            ("cgb", Some(Model::CGB)),
+            ("agb", Some(Model::AGB)),
+            ("gba", None),
+            ("", None),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(Model::parse(name), expected, "{}", name);
+        }
+    }
+}
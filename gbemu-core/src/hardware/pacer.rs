@@ -0,0 +1,73 @@
+use super::{apu::CLOCK_RATE, ppu::FRAME_DOTS};
+use std::time::{Duration, Instant};
+
+/// Frames per second of the LCD, about 59.7275 Hz
+pub const FRAME_RATE: f64 = CLOCK_RATE as f64 / FRAME_DOTS as f64;
+
+/// Pacing gives up catching up once it is this many frames behind
+const MAX_FRAMES_BEHIND: u32 = 4;
+
+/// Real-time pacer
+/// Waits between frames so they run at the rate of the hardware. Deadlines are kept on a fixed
+/// schedule instead of sleeping a frame after every frame, so the time spent emulating and
+/// oversleeping are made up for on the next frames and the rate does not drift.
+pub struct Pacer {
+    frame: Duration,
+    deadline: Instant,
+}
+
+impl Pacer {
+    /// Create a new Pacer targeting `FRAME_RATE`
+    pub fn new() -> Pacer {
+        Pacer::with_rate(FRAME_RATE)
+    }
+
+    /// Create a new Pacer targeting `rate` frames per second, e.g. to run at a multiple of the
+    /// hardware speed
+    /// Panics if `rate` is not above 0
+    pub fn with_rate(rate: f64) -> Pacer {
+        if rate.is_nan() || rate <= 0.0 {
+            panic!("ERROR: Invalid frame rate {}", rate);
+        }
+        let frame = Duration::from_secs_f64(1.0 / rate);
+        Pacer {
+            frame,
+            deadline: Instant::now() + frame,
+        }
+    }
+
+    /// Wait until the frame that was just emulated is due
+    pub fn wait(&mut self) {
+        let now = Instant::now();
+        if now < self.deadline {
+            std::thread::sleep(self.deadline - now);
+        } else if now - self.deadline > self.frame * MAX_FRAMES_BEHIND {
+            // Too slow or paused, restart the schedule instead of running fast to catch up
+            self.deadline = now;
+        }
+        self.deadline += self.frame;
+    }
+}
+
+impl Default for Pacer {
+    fn default() -> Pacer {
+        Pacer::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn zero_rate_panics() {
+        Pacer::with_rate(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_rate_panics() {
+        Pacer::with_rate(f64::NAN);
+    }
+}
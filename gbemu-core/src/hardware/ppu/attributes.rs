@@ -0,0 +1,37 @@
+#[derive(Clone, Copy, Debug)]
+/// Tile attributes
+/// Stored in VRAM bank 1 at the same address as the tile number for the BG and window maps in CGB
+/// mode, and as the fourth byte of every OAM entry for objects
+/// BIT 0-2: CGB palette number
+/// BIT 3: CGB tile VRAM bank
+/// BIT 4: DMG palette (objects only, 0 = OBP0, 1 = OBP1)
+/// BIT 5: X flip
+/// BIT 6: Y flip
+/// BIT 7: Priority (BG: BG over objects, objects: BG colours 1-3 over the object)
+pub struct Attributes(pub u8);
+
+impl Attributes {
+    pub fn cgb_palette(&self) -> usize {
+        (self.0 & 0b111) as usize
+    }
+
+    pub fn bank(&self) -> usize {
+        ((self.0 >> 3) & 1) as usize
+    }
+
+    pub fn dmg_palette(&self) -> usize {
+        ((self.0 >> 4) & 1) as usize
+    }
+
+    pub fn x_flip(&self) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    pub fn y_flip(&self) -> bool {
+        self.0 & 0x40 != 0
+    }
+
+    pub fn priority(&self) -> bool {
+        self.0 & 0x80 != 0
+    }
+}
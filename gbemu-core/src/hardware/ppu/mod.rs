@@ -0,0 +1,786 @@
+pub mod attributes;
+pub mod palette;
+
+use self::attributes::Attributes;
+use self::palette::{rgb555_to_rgb888, PaletteRam};
+use super::model::Model;
+use super::state::{Savestate, State};
+
+/// LCD registers
+/// Documented at: https://gbdev.io/pandocs/Graphics.html
+pub const LCDC: u16 = 0xFF40;
+pub const STAT: u16 = 0xFF41;
+pub const SCY: u16 = 0xFF42;
+pub const SCX: u16 = 0xFF43;
+pub const LY: u16 = 0xFF44;
+pub const LYC: u16 = 0xFF45;
+pub const BGP: u16 = 0xFF47;
+pub const OBP0: u16 = 0xFF48;
+pub const OBP1: u16 = 0xFF49;
+pub const WY: u16 = 0xFF4A;
+pub const WX: u16 = 0xFF4B;
+/// CGB palette registers
+pub const BCPS: u16 = 0xFF68;
+pub const BCPD: u16 = 0xFF69;
+pub const OCPS: u16 = 0xFF6A;
+pub const OCPD: u16 = 0xFF6B;
+
+/// Object attribute memory [0xFE00..0xFE9F], 40 entries of 4 bytes
+pub const OAM: u16 = 0xFE00;
+pub const OAM_END: u16 = 0xFE9F;
+
+pub const SCREEN_WIDTH: usize = 160;
+pub const SCREEN_HEIGHT: usize = 144;
+
+/// T-cycles (dots) per scanline and scanlines per frame, including VBlank
+const LINE_DOTS: u16 = 456;
+const LINES: u8 = 154;
+/// Dots per frame
+pub const FRAME_DOTS: u32 = LINE_DOTS as u32 * LINES as u32;
+/// Length of mode 2 (OAM scan) and mode 3 (drawing) in dots
+const OAM_SCAN_DOTS: u16 = 80;
+const DRAWING_DOTS: u16 = 172;
+/// At most 10 objects are drawn on a scanline
+const MAX_LINE_OBJECTS: usize = 10;
+
+/// Default DMG shades from white to black
+pub const DMG_SHADES: [[u8; 3]; 4] = [
+    [0xFF, 0xFF, 0xFF],
+    [0xAA, 0xAA, 0xAA],
+    [0x55, 0x55, 0x55],
+    [0x00, 0x00, 0x00],
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// PPU modes, valued as they read in STAT
+pub enum Mode {
+    HBlank = 0,
+    VBlank = 1,
+    OamScan = 2,
+    Drawing = 3,
+}
+
+/// Picture Processing Unit
+/// Steps through the modes of every scanline and renders a whole scanline at the start of mode 3.
+/// LCDC:
+/// BIT 0: BG and window enable (DMG), BG and window priority (CGB)
+/// BIT 1: Object enable
+/// BIT 2: Object size (0 = 8x8, 1 = 8x16)
+/// BIT 3: BG tile map (0 = 0x9800, 1 = 0x9C00)
+/// BIT 4: BG and window tile data (0 = 0x8800 signed, 1 = 0x8000 unsigned)
+/// BIT 5: Window enable
+/// BIT 6: Window tile map (0 = 0x9800, 1 = 0x9C00)
+/// BIT 7: LCD enable
+/// STAT:
+/// BIT 0-1: Mode
+/// BIT 2: LY == LYC
+/// BIT 3-6: Interrupt select for mode 0, mode 1, mode 2 and LY == LYC
+pub struct Ppu {
+    cgb: bool,
+    /// On models before the CGB, writing STAT briefly enables the HBlank, VBlank and LY == LYC
+    /// interrupts, which requests a STAT interrupt if one of them is active
+    stat_write_bug: bool,
+    /// Video RAM [0x8000..0x9FFF], bank 1 is only used in CGB mode
+    vram: [[u8; 0x2000]; 2],
+    vram_bank: usize,
+    oam: [u8; 0xA0],
+    lcdc: u8,
+    stat: u8,
+    scy: u8,
+    scx: u8,
+    ly: u8,
+    lyc: u8,
+    bgp: u8,
+    obp: [u8; 2],
+    wy: u8,
+    wx: u8,
+    bg_palettes: PaletteRam,
+    obj_palettes: PaletteRam,
+    /// Colours the DMG shades are displayed with, for the BG, OBP0 and OBP1
+    dmg_colours: [[[u8; 3]; 4]; 3],
+    /// Whether CGB colours are corrected to look like the LCD
+    colour_correction: bool,
+    mode: Mode,
+    /// Dot within the current scanline
+    dot: u16,
+    /// Scanline of the window being drawn, it only advances on lines the window is visible
+    window_line: u8,
+    /// State of the STAT interrupt line, the interrupt is requested on its rising edge
+    stat_line: bool,
+    vblank_interrupt: bool,
+    stat_interrupt: bool,
+    frame_complete: bool,
+    /// Set when an HBlank of a visible scanline starts
+    hblank_started: bool,
+    /// RGB888 pixels of the screen
+    framebuffer: Vec<u8>,
+    /// DMG shades 0-3 of the pixels of the screen, after the palette registers are applied
+    shades: Vec<u8>,
+}
+
+impl Ppu {
+    /// Create a new Ppu with the LCD off
+    pub fn new() -> Ppu {
+        Ppu {
+            cgb: false,
+            stat_write_bug: true,
+            vram: [[0; 0x2000]; 2],
+            vram_bank: 0,
+            oam: [0; 0xA0],
+            lcdc: 0,
+            stat: 0,
+            scy: 0,
+            scx: 0,
+            ly: 0,
+            lyc: 0,
+            bgp: 0,
+            obp: [0; 2],
+            wy: 0,
+            wx: 0,
+            bg_palettes: PaletteRam::new(),
+            obj_palettes: PaletteRam::new(),
+            dmg_colours: [DMG_SHADES; 3],
+            colour_correction: false,
+            mode: Mode::HBlank,
+            dot: 0,
+            window_line: 0,
+            stat_line: false,
+            vblank_interrupt: false,
+            stat_interrupt: false,
+            frame_complete: false,
+            hblank_started: false,
+            framebuffer: vec![0xFF; SCREEN_WIDTH * SCREEN_HEIGHT * 3],
+            shades: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT],
+        }
+    }
+
+    /// Emulate the PPU quirks of a model
+    pub fn set_model(&mut self, model: Model) {
+        self.stat_write_bug = !model.is_cgb();
+    }
+
+    /// Render in CGB mode
+    pub fn set_cgb(&mut self, cgb: bool) {
+        self.cgb = cgb;
+    }
+
+    /// Set the colours the DMG shades are displayed with, for the BG, OBP0 and OBP1
+    pub fn set_dmg_colours(&mut self, colours: [[[u8; 3]; 4]; 3]) {
+        self.dmg_colours = colours;
+    }
+
+    /// Correct CGB colours to look like they do on the LCD
+    pub fn set_colour_correction(&mut self, correct: bool) {
+        self.colour_correction = correct;
+    }
+
+    /// Whether CGB colours are corrected
+    pub fn colour_correction(&self) -> bool {
+        self.colour_correction
+    }
+
+    /// RGB888 pixels of the screen, row by row
+    pub fn framebuffer(&self) -> &[u8] {
+        &self.framebuffer
+    }
+
+    /// DMG shades 0-3 of the screen, row by row, as sent to the LCD in DMG mode
+    /// The Super Game Boy colourizes these instead of the framebuffer
+    pub fn shades(&self) -> &[u8] {
+        &self.shades
+    }
+
+    /// Current mode
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Whether the LCD is on
+    fn enabled(&self) -> bool {
+        self.lcdc & 0x80 != 0
+    }
+
+    /// Returns and clears a pending VBlank interrupt request
+    pub fn take_vblank_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.vblank_interrupt)
+    }
+
+    /// Returns and clears a pending STAT interrupt request
+    pub fn take_stat_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.stat_interrupt)
+    }
+
+    /// Returns and clears whether a frame was completed
+    pub fn take_frame_complete(&mut self) -> bool {
+        std::mem::take(&mut self.frame_complete)
+    }
+
+    /// Returns and clears whether an HBlank of a visible scanline started
+    pub fn take_hblank_started(&mut self) -> bool {
+        std::mem::take(&mut self.hblank_started)
+    }
+
+    /// Mode of the current dot of the current scanline
+    fn current_mode(&self) -> Mode {
+        if self.ly >= SCREEN_HEIGHT as u8 {
+            Mode::VBlank
+        } else if self.dot < OAM_SCAN_DOTS {
+            Mode::OamScan
+        } else if self.dot < OAM_SCAN_DOTS + DRAWING_DOTS {
+            Mode::Drawing
+        } else {
+            Mode::HBlank
+        }
+    }
+
+    /// Dots until the next mode or scanline change, None while the LCD is off
+    pub fn dots_to_event(&self) -> Option<u32> {
+        if !self.enabled() {
+            return None;
+        }
+        // Right after the LCD is turned on the mode changes on the next dot
+        if self.current_mode() != self.mode {
+            return Some(1);
+        }
+        let next = if self.ly >= SCREEN_HEIGHT as u8 {
+            LINE_DOTS
+        } else if self.dot < OAM_SCAN_DOTS {
+            OAM_SCAN_DOTS
+        } else if self.dot < OAM_SCAN_DOTS + DRAWING_DOTS {
+            OAM_SCAN_DOTS + DRAWING_DOTS
+        } else {
+            LINE_DOTS
+        };
+        Some((next - self.dot) as u32)
+    }
+
+    /// Advance the PPU by `dots` dots
+    /// Nothing changes between mode changes, so the dots up to the next one are skipped at once
+    pub fn run(&mut self, mut dots: u32) {
+        while let Some(until) = self.dots_to_event() {
+            if dots == 0 {
+                return;
+            }
+            let step = until.min(dots);
+            self.dot += step as u16 - 1;
+            self.tick();
+            dots -= step;
+        }
+    }
+
+    /// Advance the PPU by one dot
+    pub fn tick(&mut self) {
+        if !self.enabled() {
+            return;
+        }
+        self.dot += 1;
+        if self.dot == LINE_DOTS {
+            self.dot = 0;
+            self.ly = (self.ly + 1) % LINES;
+            if self.ly == 0 {
+                self.window_line = 0;
+            }
+        }
+        let mode = self.current_mode();
+        if mode != self.mode {
+            self.mode = mode;
+            match mode {
+                Mode::Drawing => self.render_line(),
+                Mode::HBlank => self.hblank_started = true,
+                Mode::VBlank => {
+                    self.vblank_interrupt = true;
+                    self.frame_complete = true;
+                }
+                _ => {}
+            }
+        }
+        self.update_stat_line();
+    }
+
+    /// Update the STAT interrupt line, requesting the interrupt on its rising edge
+    fn update_stat_line(&mut self) {
+        let line = (self.stat & 0x40 != 0 && self.ly == self.lyc)
+            || (self.stat & 0x08 != 0 && self.mode == Mode::HBlank)
+            || (self.stat & 0x10 != 0 && self.mode == Mode::VBlank)
+            || (self.stat & 0x20 != 0 && self.mode == Mode::OamScan);
+        if line && !self.stat_line {
+            self.stat_interrupt = true;
+        }
+        self.stat_line = line;
+    }
+
+    /// DMG shade of a colour index through a palette register
+    fn dmg_shade(register: u8, index: u8) -> u8 {
+        (register >> (index * 2)) & 0b11
+    }
+
+    /// Colour of a DMG shade
+    /// `palette` is 0 for the BG and window, 1 and 2 for objects using OBP0 and OBP1
+    fn dmg_colour(&self, palette: usize, register: u8, index: u8) -> [u8; 3] {
+        self.dmg_colours[palette][Ppu::dmg_shade(register, index) as usize]
+    }
+
+    /// Colour index 0-3 of a pixel of a tile
+    fn tile_pixel(&self, bank: usize, tile_addr: usize, row: usize, column: usize) -> u8 {
+        let lo = self.vram[bank][tile_addr + row * 2];
+        let hi = self.vram[bank][tile_addr + row * 2 + 1];
+        let bit = 7 - column;
+        (lo >> bit) & 1 | ((hi >> bit) & 1) << 1
+    }
+
+    /// Render the current scanline into the framebuffer
+    fn render_line(&mut self) {
+        let ly = self.ly as usize;
+        // Colour index and priority of the BG and window, used to resolve object priority
+        let mut bg_index = [0_u8; SCREEN_WIDTH];
+        let mut bg_priority = [false; SCREEN_WIDTH];
+        let mut line = [[0_u8; 3]; SCREEN_WIDTH];
+        let mut shades = [0_u8; SCREEN_WIDTH];
+
+        // In DMG mode LCDC bit 0 turns the BG and window off, in CGB mode it only drops their priority
+        let bg_enabled = self.cgb || self.lcdc & 0x01 != 0;
+        let window_visible =
+            bg_enabled && self.lcdc & 0x20 != 0 && self.ly >= self.wy && self.wx <= 166;
+        for (x, pixel) in line.iter_mut().enumerate() {
+            if !bg_enabled {
+                *pixel = self.dmg_colour(0, self.bgp, 0);
+                shades[x] = Ppu::dmg_shade(self.bgp, 0);
+                continue;
+            }
+            let in_window = window_visible && x + 7 >= self.wx as usize;
+            let (map, tx, ty) = if in_window {
+                let map = if self.lcdc & 0x40 != 0 {
+                    0x1C00
+                } else {
+                    0x1800
+                };
+                (map, x + 7 - self.wx as usize, self.window_line as usize)
+            } else {
+                let map = if self.lcdc & 0x08 != 0 {
+                    0x1C00
+                } else {
+                    0x1800
+                };
+                (
+                    map,
+                    (self.scx as usize + x) & 0xFF,
+                    (self.scy as usize + ly) & 0xFF,
+                )
+            };
+            let map_addr = map + (ty / 8) * 32 + tx / 8;
+            let tile = self.vram[0][map_addr];
+            let attributes = Attributes(if self.cgb { self.vram[1][map_addr] } else { 0 });
+            let tile_addr = if self.lcdc & 0x10 != 0 {
+                tile as usize * 16
+            } else {
+                (0x1000 + (tile as i8 as i32) * 16) as usize
+            };
+            let row = if attributes.y_flip() {
+                7 - ty % 8
+            } else {
+                ty % 8
+            };
+            let column = if attributes.x_flip() {
+                7 - tx % 8
+            } else {
+                tx % 8
+            };
+            let index = self.tile_pixel(attributes.bank(), tile_addr, row, column);
+            bg_index[x] = index;
+            bg_priority[x] = attributes.priority();
+            *pixel = if self.cgb {
+                let colour = self
+                    .bg_palettes
+                    .rgb555(attributes.cgb_palette(), index as usize);
+                rgb555_to_rgb888(colour, self.colour_correction)
+            } else {
+                shades[x] = Ppu::dmg_shade(self.bgp, index);
+                self.dmg_colour(0, self.bgp, index)
+            };
+        }
+        if window_visible {
+            self.window_line += 1;
+        }
+
+        if self.lcdc & 0x02 != 0 {
+            self.render_objects(&mut line, &mut shades, &bg_index, &bg_priority);
+        }
+
+        let start = ly * SCREEN_WIDTH * 3;
+        for (x, pixel) in line.iter().enumerate() {
+            self.framebuffer[start + x * 3..start + x * 3 + 3].copy_from_slice(pixel);
+        }
+        self.shades[ly * SCREEN_WIDTH..(ly + 1) * SCREEN_WIDTH].copy_from_slice(&shades);
+    }
+
+    /// Draw the objects of the current scanline over the BG and window
+    /// In DMG mode the object with the smallest X wins where objects overlap, in CGB mode the one
+    /// that comes first in OAM
+    fn render_objects(
+        &self,
+        line: &mut [[u8; 3]; SCREEN_WIDTH],
+        shades: &mut [u8; SCREEN_WIDTH],
+        bg_index: &[u8],
+        bg_priority: &[bool],
+    ) {
+        let height = if self.lcdc & 0x04 != 0 { 16 } else { 8 };
+        let ly = self.ly as i16;
+        let mut objects: Vec<usize> = (0..40)
+            .filter(|i| {
+                let y = self.oam[i * 4] as i16 - 16;
+                ly >= y && ly < y + height
+            })
+            .take(MAX_LINE_OBJECTS)
+            .collect();
+        if !self.cgb {
+            // Stable sort keeps OAM order for objects at the same X
+            objects.sort_by_key(|i| self.oam[i * 4 + 1]);
+        }
+        // Draw from lowest to highest priority so the winner is drawn last
+        for &i in objects.iter().rev() {
+            let y = self.oam[i * 4] as i16 - 16;
+            let x = self.oam[i * 4 + 1] as i16 - 8;
+            let mut tile = self.oam[i * 4 + 2] as usize;
+            let attributes = Attributes(self.oam[i * 4 + 3]);
+            if height == 16 {
+                tile &= 0xFE;
+            }
+            let mut row = (ly - y) as usize;
+            if attributes.y_flip() {
+                row = height as usize - 1 - row;
+            }
+            let bank = if self.cgb { attributes.bank() } else { 0 };
+            for column in 0..8 {
+                let px = x + column;
+                if !(0..SCREEN_WIDTH as i16).contains(&px) {
+                    continue;
+                }
+                let px = px as usize;
+                let column = if attributes.x_flip() {
+                    7 - column
+                } else {
+                    column
+                } as usize;
+                let index = self.tile_pixel(bank, tile * 16, row, column);
+                if index == 0 {
+                    continue;
+                }
+                // In CGB mode LCDC bit 0 cleared puts objects over everything
+                let master_priority = !self.cgb || self.lcdc & 0x01 != 0;
+                let bg_wins = bg_index[px] != 0
+                    && master_priority
+                    && (attributes.priority() || (self.cgb && bg_priority[px]));
+                if bg_wins {
+                    continue;
+                }
+                line[px] = if self.cgb {
+                    let colour = self
+                        .obj_palettes
+                        .rgb555(attributes.cgb_palette(), index as usize);
+                    rgb555_to_rgb888(colour, self.colour_correction)
+                } else {
+                    let palette = attributes.dmg_palette();
+                    shades[px] = Ppu::dmg_shade(self.obp[palette], index);
+                    self.dmg_colour(1 + palette, self.obp[palette], index)
+                };
+            }
+        }
+    }
+
+    /// The 4 KiB a Super Game Boy VRAM transfer reads from the screen
+    /// Games display 256 tiles in order from the top left of the BG, the data of those tiles is
+    /// read in the order they are displayed
+    pub fn vram_transfer_data(&self) -> Vec<u8> {
+        let map = if self.lcdc & 0x08 != 0 {
+            0x1C00
+        } else {
+            0x1800
+        };
+        let mut data = Vec::with_capacity(0x1000);
+        for i in 0..256 {
+            let tile = self.vram[0][map + (i / 20) * 32 + i % 20];
+            let tile_addr = if self.lcdc & 0x10 != 0 {
+                tile as usize * 16
+            } else {
+                (0x1000 + (tile as i8 as i32) * 16) as usize
+            };
+            data.extend_from_slice(&self.vram[0][tile_addr..tile_addr + 16]);
+        }
+        data
+    }
+
+    /// Read VRAM in the selected bank
+    pub fn read_vram(&self, addr: u16) -> u8 {
+        self.vram[self.vram_bank][addr as usize - 0x8000]
+    }
+
+    /// Write VRAM in the selected bank
+    pub fn write_vram(&mut self, addr: u16, val: u8) {
+        self.vram[self.vram_bank][addr as usize - 0x8000] = val;
+    }
+
+    /// Select the VRAM bank, only possible in CGB mode
+    pub fn set_vram_bank(&mut self, bank: usize) {
+        if self.cgb {
+            self.vram_bank = bank & 1;
+        }
+    }
+
+    /// Selected VRAM bank
+    pub fn vram_bank(&self) -> usize {
+        self.vram_bank
+    }
+
+    /// Read OAM
+    pub fn read_oam(&self, addr: u16) -> u8 {
+        self.oam[(addr - OAM) as usize]
+    }
+
+    /// Write OAM
+    pub fn write_oam(&mut self, addr: u16, val: u8) {
+        self.oam[(addr - OAM) as usize] = val;
+    }
+
+    /// Read an LCD register, unreadable bits read as 1
+    pub fn read(&self, addr: u16) -> u8 {
+        match addr {
+            LCDC => self.lcdc,
+            STAT => {
+                let mode = if self.enabled() { self.mode as u8 } else { 0 };
+                0x80 | self.stat | ((self.ly == self.lyc) as u8) << 2 | mode
+            }
+            SCY => self.scy,
+            SCX => self.scx,
+            LY => self.ly,
+            LYC => self.lyc,
+            BGP => self.bgp,
+            OBP0 => self.obp[0],
+            OBP1 => self.obp[1],
+            WY => self.wy,
+            WX => self.wx,
+            BCPS if self.cgb => self.bg_palettes.read_index(),
+            BCPD if self.cgb => self.bg_palettes.read_data(),
+            OCPS if self.cgb => self.obj_palettes.read_index(),
+            OCPD if self.cgb => self.obj_palettes.read_data(),
+            _ => 0xFF,
+        }
+    }
+
+    /// Write an LCD register
+    pub fn write(&mut self, addr: u16, val: u8) {
+        match addr {
+            LCDC => {
+                let was_enabled = self.enabled();
+                self.lcdc = val;
+                if was_enabled && !self.enabled() {
+                    // Turning the LCD off resets it to the start of the frame
+                    self.ly = 0;
+                    self.dot = 0;
+                    self.window_line = 0;
+                    self.mode = Mode::HBlank;
+                }
+            }
+            STAT => {
+                if self.stat_write_bug && self.enabled() {
+                    self.stat = 0x58;
+                    self.update_stat_line();
+                }
+                self.stat = val & 0x78;
+                self.update_stat_line();
+            }
+            SCY => self.scy = val,
+            SCX => self.scx = val,
+            LYC => self.lyc = val,
+            BGP => self.bgp = val,
+            OBP0 => self.obp[0] = val,
+            OBP1 => self.obp[1] = val,
+            WY => self.wy = val,
+            WX => self.wx = val,
+            BCPS if self.cgb => self.bg_palettes.write_index(val),
+            BCPD if self.cgb => self.bg_palettes.write_data(val),
+            OCPS if self.cgb => self.obj_palettes.write_index(val),
+            OCPD if self.cgb => self.obj_palettes.write_data(val),
+            _ => {}
+        }
+        if self.enabled() {
+            self.update_stat_line();
+        }
+    }
+}
+
+impl Default for Ppu {
+    fn default() -> Ppu {
+        Ppu::new()
+    }
+}
+
+/// The colours given to DMG shades are configuration and not part of the state
+impl Savestate for Ppu {
+    fn state(&mut self, state: &mut State) {
+        state.bool(&mut self.cgb);
+        state.bool(&mut self.stat_write_bug);
+        for bank in &mut self.vram {
+            state.bytes(bank);
+        }
+        state.usize(&mut self.vram_bank);
+        state.check(self.vram_bank < 2, "VRAM bank out of range");
+        state.bytes(&mut self.oam);
+        for register in [
+            &mut self.lcdc,
+            &mut self.stat,
+            &mut self.scy,
+            &mut self.scx,
+            &mut self.ly,
+            &mut self.lyc,
+            &mut self.bgp,
+            &mut self.wy,
+            &mut self.wx,
+        ] {
+            state.u8(register);
+        }
+        state.bytes(&mut self.obp);
+        state.check(self.ly < 154, "LY out of range");
+        self.bg_palettes.state(state);
+        self.obj_palettes.state(state);
+        let modes = [Mode::HBlank, Mode::VBlank, Mode::OamScan, Mode::Drawing];
+        let mut mode = self.mode as u8;
+        state.u8(&mut mode);
+        self.mode = modes[mode as usize & 3];
+        state.u16(&mut self.dot);
+        state.check(self.dot < 456, "PPU dot out of range");
+        state.u8(&mut self.window_line);
+        state.bool(&mut self.stat_line);
+        state.bool(&mut self.vblank_interrupt);
+        state.bool(&mut self.stat_interrupt);
+        state.bool(&mut self.frame_complete);
+        state.bool(&mut self.hblank_started);
+        state.bytes(&mut self.framebuffer);
+        state.bytes(&mut self.shades);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// PPU in CGB or DMG mode with the LCD off
+    fn ppu(cgb: bool) -> Ppu {
+        let mut ppu = Ppu::new();
+        ppu.set_cgb(cgb);
+        ppu
+    }
+
+    /// Turn the LCD on with `lcdc` and draw a frame
+    fn draw(ppu: &mut Ppu, lcdc: u8) {
+        ppu.write(LCDC, lcdc);
+        ppu.run(FRAME_DOTS);
+    }
+
+    fn pixel(ppu: &Ppu, x: usize, y: usize) -> [u8; 3] {
+        let start = (y * SCREEN_WIDTH + x) * 3;
+        ppu.framebuffer()[start..start + 3].try_into().unwrap()
+    }
+
+    /// Write a CGB palette colour through the index and data registers
+    fn set_colour(ppu: &mut Ppu, index: u16, palette: usize, colour: usize, val: u16) {
+        ppu.write(index, 0x80 | (palette * 8 + colour * 2) as u8);
+        ppu.write(index + 1, val as u8);
+        ppu.write(index + 1, (val >> 8) as u8);
+    }
+
+    #[test]
+    fn palette_index_auto_increment_wraps() {
+        let mut ppu = ppu(true);
+        for (index, data) in [(BCPS, BCPD), (OCPS, OCPD)] {
+            ppu.write(index, 0x80 | 0x3E);
+            ppu.write(data, 0x11);
+            ppu.write(data, 0x22);
+            assert_eq!(ppu.read(index), 0xC0);
+            ppu.write(data, 0x33);
+            assert_eq!(ppu.read(index), 0xC1);
+            // Without auto-increment the index stays
+            ppu.write(index, 0x3F);
+            assert_eq!(ppu.read(data), 0x22);
+            ppu.write(data, 0x44);
+            assert_eq!(ppu.read(index), 0x7F);
+            ppu.write(index, 0x00);
+            assert_eq!(ppu.read(data), 0x33);
+        }
+        assert_eq!(ppu.bg_palettes.rgb555(7, 3), 0x4411);
+        assert_eq!(ppu.obj_palettes.rgb555(7, 3), 0x4411);
+    }
+
+    #[test]
+    fn bg_attributes_flip_and_select_the_bank() {
+        let mut ppu = ppu(true);
+        // Tile 0 is solid colour 3 in bank 0, and a single pixel at the top left in bank 1
+        for addr in 0x8000..0x8010 {
+            ppu.write_vram(addr, 0xFF);
+        }
+        ppu.set_vram_bank(1);
+        ppu.write_vram(0x8000, 0x80);
+        ppu.write_vram(0x8001, 0x80);
+        // Palette 2, bank 1, X and Y flip for the first tile of the map
+        ppu.write_vram(0x9800, 0x08 | 0x20 | 0x40 | 2);
+        set_colour(&mut ppu, BCPS, 2, 3, 0x001F);
+        draw(&mut ppu, 0x91);
+
+        let red = rgb555_to_rgb888(0x001F, false);
+        assert_eq!(pixel(&ppu, 7, 7), red);
+        assert_eq!(pixel(&ppu, 0, 0), [0, 0, 0]);
+        assert_eq!(pixel(&ppu, 0, 7), [0, 0, 0]);
+        assert_eq!(pixel(&ppu, 7, 0), [0, 0, 0]);
+        // The other tiles of the map use palette 0 and bank 0
+        assert_eq!(pixel(&ppu, 8, 0), [0, 0, 0]);
+        assert_eq!(pixel(&ppu, 8, 8), [0, 0, 0]);
+    }
+
+    /// Objects overlapping at X 12-15, the first in OAM at X 12 in colour 1, the second at X 8 in
+    /// colour 2
+    fn overlapping_objects(ppu: &mut Ppu) {
+        for (i, (x, tile, lo, hi)) in [(20, 1, 0xFF, 0x00), (16, 2, 0x00, 0xFF)]
+            .into_iter()
+            .enumerate()
+        {
+            let entry = OAM + i as u16 * 4;
+            ppu.write_oam(entry, 16);
+            ppu.write_oam(entry + 1, x);
+            ppu.write_oam(entry + 2, tile);
+            ppu.write_oam(entry + 3, 0);
+            for row in 0..8 {
+                ppu.write_vram(0x8000 + tile as u16 * 16 + row * 2, lo);
+                ppu.write_vram(0x8000 + tile as u16 * 16 + row * 2 + 1, hi);
+            }
+        }
+    }
+
+    #[test]
+    fn object_priority_by_x_on_dmg() {
+        let mut ppu = ppu(false);
+        overlapping_objects(&mut ppu);
+        ppu.write(OBP0, 0xE4);
+        draw(&mut ppu, 0x93);
+        let shade = |x: usize| ppu.shades()[x];
+        // The object with the smaller X wins
+        assert_eq!(shade(8), 2);
+        assert_eq!(shade(12), 2);
+        assert_eq!(shade(15), 2);
+        assert_eq!(shade(16), 1);
+    }
+
+    #[test]
+    fn object_priority_by_oam_index_on_cgb() {
+        let mut ppu = ppu(true);
+        overlapping_objects(&mut ppu);
+        set_colour(&mut ppu, OCPS, 0, 1, 0x001F);
+        set_colour(&mut ppu, OCPS, 0, 2, 0x03E0);
+        draw(&mut ppu, 0x93);
+        let red = rgb555_to_rgb888(0x001F, false);
+        let green = rgb555_to_rgb888(0x03E0, false);
+        // The object first in OAM wins
+        assert_eq!(pixel(&ppu, 8, 0), green);
+        assert_eq!(pixel(&ppu, 12, 0), red);
+        assert_eq!(pixel(&ppu, 15, 0), red);
+        assert_eq!(pixel(&ppu, 16, 0), red);
+    }
+}
@@ -0,0 +1,92 @@
+use super::super::state::{Savestate, State};
+
+/// CGB palette RAM
+/// Holds 8 palettes of 4 colours, each colour is 2 bytes of little-endian RGB555:
+/// BIT 0-4: Red, BIT 5-9: Green, BIT 10-14: Blue
+/// Accessed through an index register (BCPS/OCPS) and a data register (BCPD/OCPD):
+/// BIT 0-5: Byte index into palette RAM
+/// BIT 7: Auto-increment the index after writing the data register
+pub struct PaletteRam {
+    data: [u8; 64],
+    index: u8,
+}
+
+impl PaletteRam {
+    /// Create a new PaletteRam
+    pub fn new() -> PaletteRam {
+        PaletteRam {
+            data: [0; 64],
+            index: 0,
+        }
+    }
+
+    /// Read the index register
+    pub fn read_index(&self) -> u8 {
+        self.index | 0x40
+    }
+
+    /// Write the index register
+    pub fn write_index(&mut self, val: u8) {
+        self.index = val & 0xBF;
+    }
+
+    /// Read the data register
+    pub fn read_data(&self) -> u8 {
+        self.data[(self.index & 0x3F) as usize]
+    }
+
+    /// Write the data register, incrementing the index if auto-increment is set
+    pub fn write_data(&mut self, val: u8) {
+        self.data[(self.index & 0x3F) as usize] = val;
+        if self.index & 0x80 != 0 {
+            self.index = 0x80 | (self.index + 1) & 0x3F;
+        }
+    }
+
+    /// RGB555 value of a colour of a palette
+    pub fn rgb555(&self, palette: usize, colour: usize) -> u16 {
+        let addr = palette * 8 + colour * 2;
+        u16::from_le_bytes([self.data[addr], self.data[addr + 1]])
+    }
+
+    /// Set a colour of a palette to an RGB555 value
+    pub fn set_rgb555(&mut self, palette: usize, colour: usize, val: u16) {
+        let addr = palette * 8 + colour * 2;
+        self.data[addr..addr + 2].copy_from_slice(&val.to_le_bytes());
+    }
+}
+
+impl Default for PaletteRam {
+    fn default() -> PaletteRam {
+        PaletteRam::new()
+    }
+}
+
+/// Convert an RGB555 colour to RGB888
+/// With `correct` set, the colours are mixed and darkened to look like they do on the CGB LCD,
+/// otherwise each 5-bit channel is scaled to 8 bits
+pub fn rgb555_to_rgb888(val: u16, correct: bool) -> [u8; 3] {
+    let r = (val & 0x1F) as u32;
+    let g = ((val >> 5) & 0x1F) as u32;
+    let b = ((val >> 10) & 0x1F) as u32;
+    if correct {
+        [
+            ((r * 26 + g * 4 + b * 2).min(960) >> 2) as u8,
+            ((g * 24 + b * 8).min(960) >> 2) as u8,
+            ((r * 6 + g * 4 + b * 22).min(960) >> 2) as u8,
+        ]
+    } else {
+        [
+            (r << 3 | r >> 2) as u8,
+            (g << 3 | g >> 2) as u8,
+            (b << 3 | b >> 2) as u8,
+        ]
+    }
+}
+
+impl Savestate for PaletteRam {
+    fn state(&mut self, state: &mut State) {
+        state.bytes(&mut self.data);
+        state.u8(&mut self.index);
+    }
+}
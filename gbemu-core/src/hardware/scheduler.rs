@@ -0,0 +1,79 @@
+/// Scheduler
+/// Components are not ticked every T-cycle. Each one schedules the time of its next interesting
+/// event, such as a PPU mode change or a timer overflow, and is only brought up to date when such
+/// an event is due or when its registers are accessed. The CPU runs freely in between.
+/// Times are in CPU T-cycles since power on.
+pub struct Scheduler {
+    now: u64,
+    /// Pending events ordered by time, at most one of each kind
+    events: Vec<(u64, Event)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Kinds of events
+pub enum Event {
+    /// PPU mode or scanline change
+    Ppu,
+    /// Timer interrupt after a TIMA overflow
+    Timer,
+    /// Serial transfer completion or link poll
+    Serial,
+    /// Falling edge of DIV bit 4, stepping the APU frame sequencer
+    FrameSequencer,
+}
+
+impl Scheduler {
+    pub fn new() -> Scheduler {
+        Scheduler {
+            now: 0,
+            events: Vec::new(),
+        }
+    }
+
+    /// Current time
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Restart at `now` without any pending event
+    pub fn reset(&mut self, now: u64) {
+        self.now = now;
+        self.events.clear();
+    }
+
+    /// Advance the current time by `cycles`
+    pub fn advance(&mut self, cycles: u32) {
+        self.now += cycles as u64;
+    }
+
+    /// Schedule `event` at `time`, replacing the time it was scheduled at
+    pub fn schedule(&mut self, event: Event, time: u64) {
+        self.cancel(event);
+        let index = self.events.partition_point(|&(at, _)| at <= time);
+        self.events.insert(index, (time, event));
+    }
+
+    /// Cancel `event` if it is scheduled
+    pub fn cancel(&mut self, event: Event) {
+        self.events.retain(|&(_, scheduled)| scheduled != event);
+    }
+
+    /// Time of the earliest event
+    pub fn next(&self) -> Option<u64> {
+        self.events.first().map(|&(time, _)| time)
+    }
+
+    /// Remove and return the earliest event if it is due
+    pub fn pop_due(&mut self) -> Option<(u64, Event)> {
+        match self.events.first() {
+            Some(&(time, _)) if time <= self.now => Some(self.events.remove(0)),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Scheduler {
+        Scheduler::new()
+    }
+}
@@ -0,0 +1,179 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+/// T-cycles between polls of a link that can clock transfers
+pub const POLL_INTERVAL: u16 = 512;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// What the serial port is doing, as seen by the link when it is polled
+pub enum Port {
+    /// No transfer requested
+    Idle,
+    /// A transfer is armed for the external clock, with SB
+    Armed(u8),
+    /// A transfer is clocked by this end with its internal clock
+    Clocking,
+}
+
+/// SerialLink trait for the other end of the link cable
+/// This trait is used to swap what is plugged into the serial port
+/// Times are emulated T-cycles of this end, counted by its serial port.
+pub trait SerialLink {
+    /// Shift a full byte out as the clock master at `now`
+    /// Returns the byte shifted in from the other end, or None if it is held until the other end
+    /// answers, the transfer then completes when `poll` returns the byte
+    fn transfer(&mut self, out: u8, now: u64) -> Option<u8>;
+
+    /// Poll the link, every `POLL_INTERVAL` T-cycles and at the times of `next_delivery`
+    /// Returns the byte shifted in if a transfer of this end completed at `now`
+    fn poll(&mut self, _port: Port, _now: u64) -> Option<u8> {
+        None
+    }
+
+    /// Whether the other end can clock transfers or hold them, only then is the link polled
+    fn can_clock(&self) -> bool {
+        false
+    }
+
+    /// Time at which the link has to be polled to deliver a byte, if it is waiting for one
+    fn next_delivery(&self) -> Option<u64> {
+        None
+    }
+
+    /// Finish the output of the link, such as a sheet being printed
+    /// Returns the first error of the link since the last call, transfers can not return them
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Nothing plugged in, the data line floats high
+pub struct Disconnected;
+
+impl SerialLink for Disconnected {
+    fn transfer(&mut self, _out: u8, _now: u64) -> Option<u8> {
+        Some(0xFF)
+    }
+}
+
+/// Logs every byte sent to stdout
+/// Test ROMs such as Blargg's print their results this way
+pub struct StdoutLink;
+
+impl SerialLink for StdoutLink {
+    fn transfer(&mut self, out: u8, _now: u64) -> Option<u8> {
+        print!("{}", out as char);
+        let _ = std::io::stdout().flush();
+        Some(0xFF)
+    }
+}
+
+#[derive(Default)]
+/// State of one end of a `LinkCable`
+struct CableEnd {
+    /// Byte clocked out by this end, held until the other end shifts it in
+    outbox: Option<u8>,
+    /// Byte shifted back to this end in exchange for its outbox
+    reply: Option<u8>,
+}
+
+/// A link cable between two emulator instances in the same process
+/// Created in pairs, one end is plugged into each `System`
+/// A byte clocked out by one end is held until the other end arms a transfer for the external
+/// clock, so the two systems can be run one after the other without dropping bytes. If the other
+/// end is clocking a transfer of its own, it does not shift and 0xFF is received.
+pub struct LinkCable {
+    ends: Rc<RefCell<[CableEnd; 2]>>,
+    side: usize,
+}
+
+impl LinkCable {
+    /// Create both ends of a cable
+    pub fn pair() -> (LinkCable, LinkCable) {
+        let ends = Rc::new(RefCell::new([CableEnd::default(), CableEnd::default()]));
+        (
+            LinkCable {
+                ends: ends.clone(),
+                side: 0,
+            },
+            LinkCable { ends, side: 1 },
+        )
+    }
+}
+
+impl SerialLink for LinkCable {
+    fn transfer(&mut self, out: u8, _now: u64) -> Option<u8> {
+        self.ends.borrow_mut()[self.side].outbox = Some(out);
+        None
+    }
+
+    fn can_clock(&self) -> bool {
+        true
+    }
+
+    fn poll(&mut self, port: Port, _now: u64) -> Option<u8> {
+        let mut ends = self.ends.borrow_mut();
+        if let Some(val) = ends[self.side].reply.take() {
+            return Some(val);
+        }
+        let peer = &mut ends[1 - self.side];
+        let val = peer.outbox?;
+        match port {
+            Port::Armed(sb) => {
+                peer.outbox = None;
+                peer.reply = Some(sb);
+                Some(val)
+            }
+            Port::Clocking => {
+                peer.outbox = None;
+                peer.reply = Some(0xFF);
+                None
+            }
+            Port::Idle => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_rom::{serial_result, serial_transfer};
+    use super::*;
+
+    #[test]
+    fn cable_exchanges_a_byte_both_ways() {
+        let mut master = serial_transfer(1, 0x42, 0x81);
+        let mut slave = serial_transfer(1, 0x99, 0x80);
+        let (master_end, slave_end) = LinkCable::pair();
+        master.memory().set_serial_link(Box::new(master_end));
+        slave.memory().set_serial_link(Box::new(slave_end));
+
+        // The master finishes shifting before the slave runs, its byte is held until then
+        for _ in 0..2 {
+            master.run_frame();
+            slave.run_frame();
+        }
+        assert_eq!(serial_result(&mut master), Some(0x99));
+        assert_eq!(serial_result(&mut slave), Some(0x42));
+    }
+
+    #[test]
+    fn cable_holds_the_byte_until_the_slave_is_armed() {
+        let mut master = serial_transfer(1, 0x42, 0x81);
+        // Arms after about two frames
+        let mut slave = serial_transfer(0x1400, 0x99, 0x80);
+        let (master_end, slave_end) = LinkCable::pair();
+        master.memory().set_serial_link(Box::new(master_end));
+        slave.memory().set_serial_link(Box::new(slave_end));
+
+        master.run_frame();
+        slave.run_frame();
+        assert_eq!(serial_result(&mut master), None);
+        for _ in 0..3 {
+            master.run_frame();
+            slave.run_frame();
+        }
+        assert_eq!(serial_result(&mut master), Some(0x99));
+        assert_eq!(serial_result(&mut slave), Some(0x42));
+    }
+}
@@ -0,0 +1,220 @@
+pub mod link;
+mod png;
+pub mod printer;
+pub mod socket;
+
+use self::link::{Disconnected, Port, SerialLink, POLL_INTERVAL};
+use super::state::{Savestate, State};
+
+/// Serial registers
+/// Documented at: https://gbdev.io/pandocs/Serial_Data_Transfer_(Link_Cable).html
+/// SB [0xFF01]: Serial transfer data
+/// SC [0xFF02]: Serial transfer control
+/// BIT 0: Clock select (0 = external clock, 1 = internal clock)
+/// BIT 1: Clock speed (CGB only, 0 = 8192 Hz, 1 = 262144 Hz)
+/// BIT 7: Transfer enable (1 = transfer requested or in progress)
+pub const SB: u16 = 0xFF01;
+pub const SC: u16 = 0xFF02;
+
+/// T-cycles per bit with the internal clock at 8192 Hz
+const SLOW_BIT_CYCLES: u16 = 512;
+/// T-cycles per bit with the CGB internal clock at 262144 Hz
+const FAST_BIT_CYCLES: u16 = 16;
+
+/// Serial port
+/// Transfers are done a whole byte at a time through a `SerialLink` once the 8 bits would have
+/// been shifted. With the internal clock this side is the clock master, the transfer completes
+/// when the link returns the byte shifted in, which may be held until the other end answers. With
+/// the external clock it waits for the other end of the link to clock it.
+pub struct Serial {
+    sb: u8,
+    sc: u8,
+    /// Whether the CGB clock speed bit is honoured
+    cgb: bool,
+    /// T-cycles until the current internally clocked transfer is shifted out
+    countdown: u16,
+    /// Whether the shifted out byte waits for the other end to answer
+    waiting: bool,
+    /// T-cycles until the link is polled again
+    poll_countdown: u16,
+    /// T-cycles run by the serial port, the time given to the link
+    time: u64,
+    link: Box<dyn SerialLink>,
+    interrupt: bool,
+}
+
+impl Serial {
+    /// Create a new Serial port with nothing plugged in
+    pub fn new() -> Serial {
+        Serial {
+            sb: 0,
+            sc: 0,
+            cgb: false,
+            countdown: 0,
+            waiting: false,
+            poll_countdown: POLL_INTERVAL,
+            time: 0,
+            link: Box::new(Disconnected),
+            interrupt: false,
+        }
+    }
+
+    /// Plug a link backend into the port
+    pub fn set_link(&mut self, link: Box<dyn SerialLink>) {
+        self.link = link;
+    }
+
+    /// Finish the output of the link, see `SerialLink::finish`
+    pub fn finish_link(&mut self) -> std::io::Result<()> {
+        self.link.finish()
+    }
+
+    /// Enable the CGB clock speed bit of SC
+    pub fn set_cgb(&mut self, cgb: bool) {
+        self.cgb = cgb;
+    }
+
+    /// Length of a full byte transfer with the internal clock in T-cycles
+    fn transfer_cycles(&self) -> u16 {
+        if self.cgb && self.sc & 0b10 != 0 {
+            FAST_BIT_CYCLES * 8
+        } else {
+            SLOW_BIT_CYCLES * 8
+        }
+    }
+
+    /// Finish the current transfer with the byte shifted in
+    fn complete(&mut self, val: u8) {
+        self.sb = val;
+        self.sc &= 0x7F;
+        self.interrupt = true;
+    }
+
+    /// Whether an internally clocked transfer is still shifting out
+    fn shifting(&self) -> bool {
+        self.sc & 0x81 == 0x81 && !self.waiting
+    }
+
+    /// Advance the serial port by one T-cycle
+    pub fn tick(&mut self) {
+        self.time += 1;
+        if self.shifting() {
+            self.countdown = self.countdown.saturating_sub(1);
+            if self.countdown == 0 {
+                match self.link.transfer(self.sb, self.time) {
+                    Some(val) => self.complete(val),
+                    None => self.waiting = true,
+                }
+            }
+        }
+        if !self.link.can_clock() {
+            return;
+        }
+        // The link is only polled once per slow bit period and when it has a byte to deliver, the
+        // other end can not clock faster than that without a CGB on both ends and polling a socket
+        // every T-cycle is expensive
+        self.poll_countdown -= 1;
+        let due = self
+            .link
+            .next_delivery()
+            .is_some_and(|time| time <= self.time);
+        if self.poll_countdown == 0 || due {
+            if self.poll_countdown == 0 {
+                self.poll_countdown = POLL_INTERVAL;
+            }
+            let port = match self.sc & 0x81 {
+                0x81 => Port::Clocking,
+                0x80 => Port::Armed(self.sb),
+                _ => Port::Idle,
+            };
+            if let Some(val) = self.link.poll(port, self.time) {
+                self.waiting = false;
+                self.complete(val);
+            }
+        }
+    }
+
+    /// T-cycles until the current transfer is shifted out or the link is polled again
+    /// None when nothing happens until SC is written
+    pub fn cycles_to_event(&self) -> Option<u32> {
+        let shifted = self.shifting().then_some(self.countdown.max(1) as u32);
+        if !self.link.can_clock() {
+            return shifted;
+        }
+        let poll = self.poll_countdown as u32;
+        let delivery = self.link.next_delivery().map_or(poll, |time| {
+            time.saturating_sub(self.time).clamp(1, poll as u64) as u32
+        });
+        Some(shifted.map_or(delivery, |shifted| shifted.min(delivery)))
+    }
+
+    /// Advance the serial port by `cycles` T-cycles
+    pub fn run(&mut self, mut cycles: u32) {
+        while cycles > 0 {
+            let step = self
+                .cycles_to_event()
+                .map_or(cycles, |until| until.min(cycles));
+            // Nothing happens before the last T-cycle of the step
+            let skipped = step - 1;
+            self.time += skipped as u64;
+            if self.shifting() {
+                self.countdown = self.countdown.saturating_sub(skipped as u16);
+            }
+            if self.link.can_clock() {
+                self.poll_countdown -= skipped as u16;
+            }
+            self.tick();
+            cycles -= step;
+        }
+    }
+
+    /// Returns and clears a pending serial interrupt request
+    pub fn take_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.interrupt)
+    }
+
+    /// Read a serial register
+    pub fn read(&self, addr: u16) -> u8 {
+        match addr {
+            SB => self.sb,
+            SC if self.cgb => self.sc | 0x7C,
+            SC => self.sc | 0x7E,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write a serial register
+    pub fn write(&mut self, addr: u16, val: u8) {
+        match addr {
+            SB => self.sb = val,
+            SC => {
+                self.sc = val & 0x83;
+                self.waiting = false;
+                if self.sc & 0x81 == 0x81 {
+                    self.countdown = self.transfer_cycles();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Serial {
+    fn default() -> Serial {
+        Serial::new()
+    }
+}
+
+/// The link is not part of the state, it stays plugged in
+impl Savestate for Serial {
+    fn state(&mut self, state: &mut State) {
+        state.u8(&mut self.sb);
+        state.u8(&mut self.sc);
+        state.bool(&mut self.cgb);
+        state.u16(&mut self.countdown);
+        state.bool(&mut self.waiting);
+        state.u16(&mut self.poll_countdown);
+        state.u64(&mut self.time);
+        state.bool(&mut self.interrupt);
+    }
+}
@@ -0,0 +1,372 @@
+use super::link::SerialLink;
+use super::png;
+use std::io;
+use std::path::PathBuf;
+
+/// Game Boy Printer
+/// Documented at: https://gbdev.io/pandocs/Gameboy_Printer.html
+///
+/// Every packet is sent by the Gameboy as the clock master:
+/// 0x88 0x33 | command | compression | length (LE16) | data | checksum (LE16) | 0x00 0x00
+/// The printer answers the last two bytes with 0x81 (alive) and its status, every other byte
+/// with 0x00. The checksum is the 16-bit sum of the bytes from command to the end of the data.
+const MAGIC: [u8; 2] = [0x88, 0x33];
+
+/// Commands
+const INIT: u8 = 0x01;
+const PRINT: u8 = 0x02;
+const DATA: u8 = 0x04;
+const STATUS: u8 = 0x0F;
+
+/// Status byte
+/// BIT 0: Checksum error
+/// BIT 1: Printer busy
+/// BIT 2: Image data full
+/// BIT 3: Unprocessed data
+const CHECKSUM_ERROR: u8 = 1 << 0;
+const BUSY: u8 = 1 << 1;
+const FULL: u8 = 1 << 2;
+const UNPROCESSED: u8 = 1 << 3;
+
+/// Printer memory holds a full screen, 20 by 18 tiles of 16 bytes
+const BUFFER_SIZE: usize = 20 * 18 * 16;
+/// The paper is one screen wide
+const WIDTH: usize = 160;
+/// Pixel rows of blank paper fed per margin unit
+const MARGIN_LINE_HEIGHT: usize = 8;
+/// Print shades for colours 0-3, from white to black
+const SHADES: [u8; 4] = [0xFF, 0xAA, 0x55, 0x00];
+
+#[derive(Clone, Copy, PartialEq)]
+/// Position within a packet
+enum State {
+    Magic(usize),
+    Command,
+    Compression,
+    Length(usize),
+    Data,
+    Checksum(usize),
+    Alive,
+    Status,
+}
+
+/// Game Boy Printer plugged into the serial port
+/// Completed prints are written as PNG files into an output directory. Images printed without a
+/// margin after them are joined into one sheet, the sheet is completed by a print with a bottom
+/// margin or when the printer is unplugged.
+pub struct GameBoyPrinter {
+    dir: PathBuf,
+    state: State,
+    command: u8,
+    compressed: bool,
+    length: u16,
+    data: Vec<u8>,
+    checksum: u16,
+    received_checksum: u16,
+    status: u8,
+    /// Decoded image data waiting to be printed
+    buffer: Vec<u8>,
+    /// Shades of the printed rows of the current sheet
+    sheet: Vec<u8>,
+    /// Number of the next file written
+    count: usize,
+    /// First error writing a print, returned by `finish`
+    error: Option<io::Error>,
+}
+
+impl GameBoyPrinter {
+    /// Create a new printer writing to a directory, which is created if needed
+    pub fn new(dir: PathBuf) -> std::io::Result<GameBoyPrinter> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(GameBoyPrinter {
+            dir,
+            state: State::Magic(0),
+            command: 0,
+            compressed: false,
+            length: 0,
+            data: Vec::new(),
+            checksum: 0,
+            received_checksum: 0,
+            status: 0,
+            buffer: Vec::new(),
+            sheet: Vec::new(),
+            count: 0,
+            error: None,
+        })
+    }
+
+    /// Decompress packet data
+    /// A control byte with bit 7 set repeats the next byte (control & 0x7F) + 2 times,
+    /// otherwise the next (control + 1) bytes are copied as is
+    fn decompress(data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < data.len() {
+            let control = data[i];
+            i += 1;
+            if control & 0x80 != 0 {
+                if let Some(byte) = data.get(i) {
+                    out.extend(std::iter::repeat_n(*byte, (control & 0x7F) as usize + 2));
+                }
+                i += 1;
+            } else {
+                let end = (i + control as usize + 1).min(data.len());
+                out.extend_from_slice(&data[i..end]);
+                i = end;
+            }
+        }
+        out
+    }
+
+    /// Execute the command of a packet once it has been received
+    fn execute(&mut self) {
+        if self.checksum != self.received_checksum {
+            self.status |= CHECKSUM_ERROR;
+            return;
+        }
+        self.status &= !CHECKSUM_ERROR;
+        match self.command {
+            INIT => {
+                self.buffer.clear();
+                self.status = 0;
+            }
+            DATA => {
+                let data = if self.compressed {
+                    GameBoyPrinter::decompress(&self.data)
+                } else {
+                    std::mem::take(&mut self.data)
+                };
+                self.buffer.extend_from_slice(&data);
+                self.buffer.truncate(BUFFER_SIZE);
+                if !self.buffer.is_empty() {
+                    self.status |= UNPROCESSED;
+                }
+                if self.buffer.len() == BUFFER_SIZE {
+                    self.status |= FULL;
+                }
+            }
+            PRINT if self.data.len() >= 4 => {
+                let (margins, palette) = (self.data[1], self.data[2]);
+                self.print(margins >> 4, margins & 0x0F, palette);
+                self.status = BUSY;
+            }
+            _ => {}
+        }
+    }
+
+    /// Render the buffer onto the current sheet with the print palette and margins
+    fn print(&mut self, margin_before: u8, margin_after: u8, palette: u8) {
+        // A palette of 0 is treated as the default 0xE4 by the printer
+        let palette = if palette == 0 { 0xE4 } else { palette };
+        self.feed(margin_before);
+        let rows = self.buffer.len() / (20 * 16) * 8;
+        for y in 0..rows {
+            for x in 0..WIDTH {
+                let tile = (y / 8) * 20 + x / 8;
+                let addr = tile * 16 + (y % 8) * 2;
+                let bit = 7 - (x % 8);
+                let colour =
+                    (self.buffer[addr] >> bit) & 1 | ((self.buffer[addr + 1] >> bit) & 1) << 1;
+                self.sheet
+                    .push(SHADES[((palette >> (colour * 2)) & 0b11) as usize]);
+            }
+        }
+        self.buffer.clear();
+        if margin_after != 0 {
+            self.feed(margin_after);
+            if let Err(e) = self.finish_sheet() {
+                self.error.get_or_insert(e);
+            }
+        }
+    }
+
+    /// Feed blank paper
+    fn feed(&mut self, lines: u8) {
+        let len = self.sheet.len() + lines as usize * MARGIN_LINE_HEIGHT * WIDTH;
+        self.sheet.resize(len, SHADES[0]);
+    }
+
+    /// Write the current sheet to the next PNG file in the output directory
+    fn finish_sheet(&mut self) -> io::Result<()> {
+        if self.sheet.is_empty() {
+            return Ok(());
+        }
+        let path = loop {
+            self.count += 1;
+            let path = self.dir.join(format!("print_{:04}.png", self.count));
+            if !path.exists() {
+                break path;
+            }
+        };
+        let rgb: Vec<u8> = self.sheet.iter().flat_map(|shade| [*shade; 3]).collect();
+        let height = (self.sheet.len() / WIDTH) as u32;
+        self.sheet.clear();
+        png::write_rgb(&path, WIDTH as u32, height, &rgb).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Could not write print to {}: {}", path.display(), e),
+            )
+        })
+    }
+}
+
+impl SerialLink for GameBoyPrinter {
+    fn transfer(&mut self, out: u8, _now: u64) -> Option<u8> {
+        let mut reply = 0x00;
+        self.state = match self.state {
+            State::Magic(i) if out == MAGIC[i] => {
+                if i == 0 {
+                    State::Magic(1)
+                } else {
+                    State::Command
+                }
+            }
+            State::Magic(_) => State::Magic(0),
+            State::Command => {
+                self.command = out;
+                self.checksum = out as u16;
+                State::Compression
+            }
+            State::Compression => {
+                self.compressed = out & 1 != 0;
+                self.checksum = self.checksum.wrapping_add(out as u16);
+                State::Length(0)
+            }
+            State::Length(i) => {
+                self.checksum = self.checksum.wrapping_add(out as u16);
+                if i == 0 {
+                    self.length = out as u16;
+                    State::Length(1)
+                } else {
+                    self.length |= (out as u16) << 8;
+                    self.data.clear();
+                    if self.length == 0 {
+                        State::Checksum(0)
+                    } else {
+                        State::Data
+                    }
+                }
+            }
+            State::Data => {
+                self.checksum = self.checksum.wrapping_add(out as u16);
+                self.data.push(out);
+                if self.data.len() == self.length as usize {
+                    State::Checksum(0)
+                } else {
+                    State::Data
+                }
+            }
+            State::Checksum(0) => {
+                self.received_checksum = out as u16;
+                State::Checksum(1)
+            }
+            State::Checksum(_) => {
+                self.received_checksum |= (out as u16) << 8;
+                State::Alive
+            }
+            State::Alive => {
+                reply = 0x81;
+                State::Status
+            }
+            State::Status => {
+                // The status reported is the one from before this packet is executed
+                reply = self.status;
+                if self.command == STATUS {
+                    // Printing is done by the time the status has been read once
+                    self.status &= !BUSY;
+                }
+                self.execute();
+                State::Magic(0)
+            }
+        };
+        Some(reply)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let result = self.finish_sheet();
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => result,
+        }
+    }
+}
+
+/// A sheet left when the printer is unplugged without `finish` is still written, but an error
+/// can not be returned from here
+impl Drop for GameBoyPrinter {
+    fn drop(&mut self) {
+        let _ = self.finish_sheet();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Printer writing to a fresh directory
+    fn printer(name: &str) -> (GameBoyPrinter, PathBuf) {
+        let dir =
+            std::env::temp_dir().join(format!("gbemu-printer-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        (GameBoyPrinter::new(dir.clone()).unwrap(), dir)
+    }
+
+    /// Send a packet, `checksum` is added to the correct one, returns the last two replies
+    fn send(printer: &mut GameBoyPrinter, command: u8, data: &[u8], checksum: u16) -> [u8; 2] {
+        let len = data.len() as u16;
+        let mut packet = vec![command, 0x00, len as u8, (len >> 8) as u8];
+        packet.extend_from_slice(data);
+        let sum = packet
+            .iter()
+            .fold(checksum, |sum, byte| sum.wrapping_add(*byte as u16));
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&packet);
+        bytes.extend_from_slice(&[sum as u8, (sum >> 8) as u8, 0x00, 0x00]);
+        let replies: Vec<u8> = bytes
+            .iter()
+            .map(|byte| printer.transfer(*byte, 0).unwrap())
+            .collect();
+        assert!(replies[..replies.len() - 2].iter().all(|reply| *reply == 0));
+        [replies[replies.len() - 2], replies[replies.len() - 1]]
+    }
+
+    #[test]
+    fn decompress_runs_and_literals() {
+        let data = [0x81, 0xAA, 0x02, 0x01, 0x02, 0x03, 0x80, 0x55];
+        assert_eq!(
+            GameBoyPrinter::decompress(&data),
+            [0xAA, 0xAA, 0xAA, 0x01, 0x02, 0x03, 0x55, 0x55]
+        );
+    }
+
+    #[test]
+    fn bad_checksum_sets_the_error() {
+        let (mut printer, dir) = printer("checksum");
+        assert_eq!(send(&mut printer, INIT, &[], 1), [0x81, 0x00]);
+        assert_eq!(send(&mut printer, STATUS, &[], 0), [0x81, CHECKSUM_ERROR]);
+        assert_eq!(send(&mut printer, STATUS, &[], 0), [0x81, 0x00]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn print_writes_a_sheet_with_margins() {
+        let (mut printer, dir) = printer("print");
+        send(&mut printer, INIT, &[], 0);
+        // Two rows of tiles
+        send(&mut printer, DATA, &[0xFF; 2 * 20 * 16], 0);
+        assert_eq!(send(&mut printer, STATUS, &[], 0), [0x81, UNPROCESSED]);
+        // One margin unit before and three after, default palette
+        send(&mut printer, PRINT, &[0x01, 0x13, 0xE4, 0x40], 0);
+        assert_eq!(send(&mut printer, STATUS, &[], 0), [0x81, BUSY]);
+        assert_eq!(send(&mut printer, STATUS, &[], 0), [0x81, 0x00]);
+
+        let png = std::fs::read(dir.join("print_0001.png")).unwrap();
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        assert_eq!(width, 160);
+        assert_eq!(height as usize, (1 + 3) * MARGIN_LINE_HEIGHT + 2 * 8);
+        printer.finish().unwrap();
+        assert!(!dir.join("print_0002.png").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,470 @@
+use super::link::{Port, SerialLink, POLL_INTERVAL};
+use std::collections::VecDeque;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::time::Duration;
+
+/// Sent by both ends on connection, followed by the protocol version and the time of the sender
+const MAGIC: &[u8; 4] = b"GBLK";
+const VERSION: u8 = 2;
+
+/// Messages are a kind, a data byte and the time of the sender in T-cycles (u64 little endian)
+/// SYNC: the sender ran until this time, sent on every poll
+/// TRANSFER: the sender clocked out a full byte as the clock master
+/// REPLY: the byte the clock slave shifted out in exchange
+const SYNC: u8 = 0x00;
+const TRANSFER: u8 = 0x01;
+const REPLY: u8 = 0x02;
+const MESSAGE_SIZE: usize = 10;
+
+/// T-cycles after it was sent at which a message takes effect on the other end
+/// An end never runs further than this ahead of the last time it heard of from the other end, so
+/// every message arrives before it takes effect. At least `POLL_INTERVAL` for both ends to run.
+const LATENCY: u64 = 2048;
+
+/// How long to wait for the other end before treating the link as disconnected
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Polls between attempts to reconnect a disconnected link
+const RECONNECT_POLLS: u32 = 1024;
+
+/// Stream trait for the sockets a `SocketLink` can run over
+trait Stream: Read + Write {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()>;
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()>;
+}
+
+impl Stream for TcpStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        TcpStream::set_nonblocking(self, nonblocking)
+    }
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        TcpStream::set_read_timeout(self, timeout)
+    }
+}
+
+#[cfg(unix)]
+impl Stream for UnixStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        UnixStream::set_nonblocking(self, nonblocking)
+    }
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        UnixStream::set_read_timeout(self, timeout)
+    }
+}
+
+/// Where the other end of a `SocketLink` is found, kept to reconnect
+enum Endpoint {
+    TcpListener(TcpListener),
+    TcpAddress(String),
+    #[cfg(unix)]
+    UnixListener(UnixListener),
+    #[cfg(unix)]
+    UnixPath(String),
+}
+
+impl Endpoint {
+    /// Connect to the other end, listeners only wait for it if `block` is set
+    fn connect(&self, block: bool) -> io::Result<Box<dyn Stream>> {
+        let stream: Box<dyn Stream> = match self {
+            Endpoint::TcpListener(listener) => {
+                listener.set_nonblocking(!block)?;
+                let (stream, _) = listener.accept()?;
+                stream.set_nodelay(true)?;
+                Box::new(stream)
+            }
+            Endpoint::TcpAddress(addr) => {
+                let stream = TcpStream::connect(addr)?;
+                stream.set_nodelay(true)?;
+                Box::new(stream)
+            }
+            #[cfg(unix)]
+            Endpoint::UnixListener(listener) => {
+                listener.set_nonblocking(!block)?;
+                Box::new(listener.accept()?.0)
+            }
+            #[cfg(unix)]
+            Endpoint::UnixPath(path) => Box::new(UnixStream::connect(path)?),
+        };
+        // Accepted sockets may inherit non-blocking mode from the listener
+        stream.set_nonblocking(false)?;
+        Ok(stream)
+    }
+}
+
+/// Link cable between two emulator processes over a local TCP or Unix domain socket
+///
+/// After connecting, both ends exchange a handshake to check they speak the same protocol and to
+/// agree on a common time. Both ends then run in lockstep: every message is stamped with the time
+/// of its sender and takes effect `LATENCY` T-cycles later on the other end, and an end waits in
+/// `poll` whenever it would otherwise run past a time the other end has not reached yet. Transfers
+/// therefore complete at the same emulated time on both ends, however the processes are scheduled.
+///
+/// Whichever end starts a transfer with the internal clock is the clock master for that byte: it
+/// sends TRANSFER and waits for REPLY. When the TRANSFER takes effect, the clock slave answers with
+/// its SB if a transfer is armed for the external clock, and both ends complete the transfer when
+/// the REPLY takes effect. If the slave is not armed yet the byte is held until it is, so no byte
+/// is lost. If the slave is clocking a transfer of its own it does not shift and answers 0xFF.
+///
+/// If the other process goes away, the link behaves as if the cable was unplugged until a process
+/// connects again, either end keeps trying to reconnect.
+pub struct SocketLink {
+    endpoint: Endpoint,
+    stream: Option<Box<dyn Stream>>,
+    /// Bytes received but not yet forming a whole message
+    pending: Vec<u8>,
+    /// Added to times of the other end to get times of this end
+    offset: u64,
+    /// Latest time the other end ran until, in times of this end
+    peer_time: u64,
+    /// Kind, byte and due time of the messages that have yet to take effect, in order
+    inbox: VecDeque<(u8, u8, u64)>,
+    /// Byte of a TRANSFER of the other end that waits for this end to be armed
+    held: Option<u8>,
+    /// Byte shifted in as the clock slave and the time the transfer completes at
+    accepted: Option<(u8, u64)>,
+    /// Whether a TRANSFER of this end waits for its REPLY
+    awaiting: bool,
+    /// Polls until the next attempt to reconnect
+    reconnect_countdown: u32,
+}
+
+impl SocketLink {
+    /// Open a link from an address of the form `tcp:HOST:PORT` or `unix:PATH`
+    /// If `listen` is set, waits for the other process to connect, otherwise connects to it
+    pub fn open(addr: &str, listen: bool) -> io::Result<SocketLink> {
+        match addr.split_once(':') {
+            Some(("tcp", addr)) if listen => SocketLink::tcp_listen(addr),
+            Some(("tcp", addr)) => SocketLink::tcp_connect(addr),
+            #[cfg(unix)]
+            Some(("unix", path)) if listen => SocketLink::unix_listen(path),
+            #[cfg(unix)]
+            Some(("unix", path)) => SocketLink::unix_connect(path),
+            _ => Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid link address: {}", addr),
+            )),
+        }
+    }
+
+    /// Wait for a connection on a TCP address
+    pub fn tcp_listen(addr: &str) -> io::Result<SocketLink> {
+        SocketLink::connect(Endpoint::TcpListener(TcpListener::bind(addr)?))
+    }
+
+    /// Connect to a TCP address
+    pub fn tcp_connect(addr: &str) -> io::Result<SocketLink> {
+        SocketLink::connect(Endpoint::TcpAddress(addr.to_string()))
+    }
+
+    #[cfg(unix)]
+    /// Wait for a connection on a Unix domain socket, replacing a stale socket file
+    pub fn unix_listen(path: &str) -> io::Result<SocketLink> {
+        let _ = std::fs::remove_file(path);
+        SocketLink::connect(Endpoint::UnixListener(UnixListener::bind(path)?))
+    }
+
+    #[cfg(unix)]
+    /// Connect to a Unix domain socket
+    pub fn unix_connect(path: &str) -> io::Result<SocketLink> {
+        SocketLink::connect(Endpoint::UnixPath(path.to_string()))
+    }
+
+    /// Make the first connection, at power on
+    fn connect(endpoint: Endpoint) -> io::Result<SocketLink> {
+        let stream = endpoint.connect(true)?;
+        let mut link = SocketLink {
+            endpoint,
+            stream: None,
+            pending: Vec::new(),
+            offset: 0,
+            peer_time: 0,
+            inbox: VecDeque::new(),
+            held: None,
+            accepted: None,
+            awaiting: false,
+            reconnect_countdown: 0,
+        };
+        link.handshake(stream, 0)?;
+        Ok(link)
+    }
+
+    /// Exchange magic, version and the current time with the other end
+    /// Both ends take the times they connected at to be the same time
+    fn handshake(&mut self, mut stream: Box<dyn Stream>, now: u64) -> io::Result<()> {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.write_all(MAGIC)?;
+        stream.write_all(&[VERSION])?;
+        stream.write_all(&now.to_le_bytes())?;
+        let mut hello = [0; 13];
+        stream.read_exact(&mut hello)?;
+        if &hello[..4] != MAGIC || hello[4] != VERSION {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "Other end of the link is not a compatible gbEMU",
+            ));
+        }
+        let peer_now = u64::from_le_bytes(hello[5..].try_into().unwrap());
+        self.offset = now.wrapping_sub(peer_now);
+        self.peer_time = now;
+        self.stream = Some(stream);
+        Ok(())
+    }
+
+    /// Drop the connection, messages that did not take effect yet are lost with it
+    fn unplug(&mut self) {
+        self.stream = None;
+        self.pending.clear();
+        self.inbox.clear();
+        self.held = None;
+        self.reconnect_countdown = 0;
+    }
+
+    /// Try to connect again every `RECONNECT_POLLS` polls, without waiting for the other end
+    fn reconnect(&mut self, now: u64) {
+        if self.reconnect_countdown > 0 {
+            self.reconnect_countdown -= 1;
+            return;
+        }
+        self.reconnect_countdown = RECONNECT_POLLS;
+        if let Ok(stream) = self.endpoint.connect(false) {
+            if self.handshake(stream, now).is_err() {
+                self.unplug();
+                self.reconnect_countdown = RECONNECT_POLLS;
+            }
+        }
+    }
+
+    /// Send a message, unplugging the link on failure
+    fn send(&mut self, kind: u8, val: u8, now: u64) {
+        if let Some(stream) = &mut self.stream {
+            let mut message = [kind, val, 0, 0, 0, 0, 0, 0, 0, 0];
+            message[2..].copy_from_slice(&now.to_le_bytes());
+            if stream.write_all(&message).is_err() {
+                self.unplug();
+            }
+        }
+    }
+
+    /// Receive a message into the inbox, blocking only if `block` is set
+    /// Returns false if no whole message is available or the link was unplugged
+    fn receive(&mut self, block: bool) -> bool {
+        let Some(stream) = self.stream.as_mut() else {
+            return false;
+        };
+        if stream.set_nonblocking(!block).is_err() {
+            self.unplug();
+            return false;
+        }
+        while self.pending.len() < MESSAGE_SIZE {
+            let mut buf = [0; MESSAGE_SIZE];
+            match stream.read(&mut buf[..MESSAGE_SIZE - self.pending.len()]) {
+                Ok(0) => {
+                    self.unplug();
+                    return false;
+                }
+                Ok(n) => self.pending.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock && !block => return false,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(_) => {
+                    self.unplug();
+                    return false;
+                }
+            }
+        }
+        let (kind, val) = (self.pending[0], self.pending[1]);
+        let time = u64::from_le_bytes(self.pending[2..].try_into().unwrap());
+        self.pending.clear();
+        self.peer_time = time.wrapping_add(self.offset);
+        if kind != SYNC {
+            self.inbox.push_back((kind, val, self.peer_time + LATENCY));
+        }
+        true
+    }
+
+    /// Apply the messages due at `now`
+    /// Returns the byte shifted in if a transfer of this end completes
+    fn deliver(&mut self, port: Port, now: u64) -> Option<u8> {
+        if let Some((val, due)) = self.accepted {
+            if due > now {
+                return None;
+            }
+            self.accepted = None;
+            return Some(val);
+        }
+        while let Some(&(kind, val, due)) = self.inbox.front() {
+            if due > now {
+                break;
+            }
+            self.inbox.pop_front();
+            match kind {
+                REPLY if self.awaiting => {
+                    self.awaiting = false;
+                    return Some(val);
+                }
+                TRANSFER => self.held = Some(val),
+                _ => {}
+            }
+        }
+        let val = self.held?;
+        match port {
+            Port::Armed(sb) => {
+                self.held = None;
+                self.send(REPLY, sb, now);
+                self.accepted = Some((val, now + LATENCY));
+            }
+            // Neither end drives the clock of the other
+            Port::Clocking => {
+                self.held = None;
+                self.send(REPLY, 0xFF, now);
+            }
+            Port::Idle => {}
+        }
+        None
+    }
+}
+
+impl SerialLink for SocketLink {
+    fn transfer(&mut self, out: u8, now: u64) -> Option<u8> {
+        if self.stream.is_none() {
+            return Some(0xFF);
+        }
+        self.send(TRANSFER, out, now);
+        self.awaiting = true;
+        None
+    }
+
+    fn can_clock(&self) -> bool {
+        true
+    }
+
+    fn poll(&mut self, port: Port, now: u64) -> Option<u8> {
+        if self.stream.is_none() {
+            self.reconnect(now);
+        }
+        self.send(SYNC, 0, now);
+        while self.receive(false) {}
+        // Wait until this end can run until its next poll without passing the other end by more
+        // than the latency
+        while self.stream.is_some() && now + POLL_INTERVAL as u64 > self.peer_time + LATENCY {
+            self.receive(true);
+        }
+        if self.stream.is_none() && std::mem::take(&mut self.awaiting) {
+            // Unplugged during the transfer, the data line floats high
+            return Some(0xFF);
+        }
+        self.deliver(port, now)
+    }
+
+    fn next_delivery(&self) -> Option<u64> {
+        match self.accepted {
+            Some((_, due)) => Some(due),
+            None => self.inbox.front().map(|&(_, _, due)| due),
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::super::super::test_rom::{serial_result, serial_transfer};
+    use super::*;
+    use std::thread;
+
+    fn socket_path(name: &str) -> String {
+        let file = format!("gbemu-{}-{}.sock", name, std::process::id());
+        std::env::temp_dir()
+            .join(file)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Connect to `path`, retrying until the other end listens
+    fn connect(path: &str) -> SocketLink {
+        loop {
+            match SocketLink::unix_connect(path) {
+                Ok(link) => return link,
+                Err(_) => thread::sleep(Duration::from_millis(10)),
+            }
+        }
+    }
+
+    /// Run a serial transfer through `link` for up to `frames` frames until it completes
+    fn run(link: SocketLink, frames: usize, delay: u16, sb: u8, sc: u8) -> Option<u8> {
+        let mut system = serial_transfer(delay, sb, sc);
+        system.memory().set_serial_link(Box::new(link));
+        for _ in 0..frames {
+            system.run_frame();
+            if let Some(val) = serial_result(&mut system) {
+                return Some(val);
+            }
+        }
+        None
+    }
+
+    /// Poll `link` from `now` like the serial port does until a transfer completes
+    /// Returns the byte shifted in and the time the transfer completed at
+    fn complete(link: &mut SocketLink, port: Port, mut now: u64) -> (u8, u64) {
+        let mut next_poll = now + POLL_INTERVAL as u64;
+        loop {
+            now = link
+                .next_delivery()
+                .map_or(next_poll, |time| time.clamp(now + 1, next_poll));
+            if now == next_poll {
+                next_poll += POLL_INTERVAL as u64;
+            }
+            if let Some(val) = link.poll(port, now) {
+                return (val, now);
+            }
+        }
+    }
+
+    #[test]
+    fn transfers_complete_at_the_same_time() {
+        let path = socket_path("lockstep");
+        let master = {
+            let path = path.clone();
+            thread::spawn(move || {
+                let mut link = SocketLink::unix_listen(&path).unwrap();
+                assert_eq!(link.transfer(0x42, 5000), None);
+                complete(&mut link, Port::Clocking, 5000)
+            })
+        };
+        let mut link = connect(&path);
+        let slave = complete(&mut link, Port::Armed(0x99), 0);
+        let master = master.join().unwrap();
+        assert_eq!(master.0, 0x99);
+        assert_eq!(slave.0, 0x42);
+        assert_eq!(master.1, slave.1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn holds_the_byte_until_the_slave_is_armed() {
+        let path = socket_path("held");
+        let master = {
+            let path = path.clone();
+            thread::spawn(move || run(SocketLink::unix_listen(&path).unwrap(), 10, 1, 0x42, 0x81))
+        };
+        // Arms about two frames after the master finished shifting
+        let slave = run(connect(&path), 10, 0x1400, 0x99, 0x80);
+        assert_eq!(master.join().unwrap(), Some(0x99));
+        assert_eq!(slave, Some(0x42));
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn reconnects_after_the_other_end_went_away() {
+        let path = socket_path("reconnect");
+        let slave = {
+            let path = path.clone();
+            thread::spawn(move || run(SocketLink::unix_listen(&path).unwrap(), 600, 1, 0x99, 0x80))
+        };
+        drop(connect(&path));
+        let master = run(connect(&path), 10, 1, 0x42, 0x81);
+        assert_eq!(slave.join().unwrap(), Some(0x42));
+        assert_eq!(master, Some(0x99));
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,588 @@
+pub mod packet;
+
+use self::packet::PacketReceiver;
+use super::ppu::palette::rgb555_to_rgb888;
+use super::ppu::{Ppu, SCREEN_HEIGHT, SCREEN_WIDTH};
+use super::state::{Savestate, State};
+
+/// Size of the Super Game Boy picture, including the border
+pub const SGB_WIDTH: usize = 256;
+pub const SGB_HEIGHT: usize = 224;
+/// Position of the Gameboy screen within the border
+const SCREEN_X: usize = 48;
+const SCREEN_Y: usize = 40;
+/// The screen is coloured in cells of 8x8 pixels
+const CELLS_X: usize = SCREEN_WIDTH / 8;
+const CELLS_Y: usize = SCREEN_HEIGHT / 8;
+/// Attribute files hold a palette number for every cell, 2 bits per cell
+const ATTRIBUTE_FILES: usize = 45;
+const ATTRIBUTE_FILE_SIZE: usize = CELLS_X * CELLS_Y / 4;
+
+/// Commands
+/// Documented at: https://gbdev.io/pandocs/SGB_Command_Summary.html
+const PAL01: u8 = 0x00;
+const PAL23: u8 = 0x01;
+const PAL03: u8 = 0x02;
+const PAL12: u8 = 0x03;
+const ATTR_BLK: u8 = 0x04;
+const ATTR_LIN: u8 = 0x05;
+const ATTR_DIV: u8 = 0x06;
+const ATTR_CHR: u8 = 0x07;
+const PAL_SET: u8 = 0x0A;
+const PAL_TRN: u8 = 0x0B;
+const MLT_REQ: u8 = 0x11;
+const CHR_TRN: u8 = 0x13;
+const PCT_TRN: u8 = 0x14;
+const ATTR_TRN: u8 = 0x15;
+const ATTR_SET: u8 = 0x16;
+const MASK_EN: u8 = 0x17;
+
+#[derive(Clone, Copy, PartialEq)]
+/// Data the game sends through VRAM on the next frame
+enum Transfer {
+    Palettes,
+    BorderTiles(usize),
+    BorderMap,
+    AttributeFiles,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+/// Screen masking set with MASK_EN
+enum Mask {
+    None,
+    /// Keep showing the last frame
+    Freeze,
+    Black,
+    /// Fill the screen with colour 0
+    Colour0,
+}
+
+/// Super Game Boy
+/// Receives command packets through P1, colourizes the DMG shades of the screen with four
+/// palettes assigned per 8x8 cell and draws the screen inside a 256x224 border.
+pub struct Sgb {
+    receiver: PacketReceiver,
+    /// The four palettes applied to the screen, in RGB555
+    palettes: [[u16; 4]; 4],
+    /// Palettes selectable with PAL_SET, sent with PAL_TRN
+    system_palettes: Vec<[u16; 4]>,
+    /// Palette of every cell of the screen
+    attributes: [u8; CELLS_X * CELLS_Y],
+    /// Attribute files selectable with PAL_SET and ATTR_SET, sent with ATTR_TRN
+    attribute_files: Vec<u8>,
+    /// Border tiles in SNES 4bpp format, 32 bytes each, sent with CHR_TRN
+    border_tiles: Vec<u8>,
+    /// Border tile map of 32x32 entries, sent with PCT_TRN
+    /// BIT 0-7: Tile, BIT 10-12: Palette (4-7), BIT 14: X flip, BIT 15: Y flip
+    border_map: [u16; 32 * 32],
+    /// Border palettes 4-7 of 16 colours, sent with PCT_TRN
+    border_palettes: [[u16; 16]; 4],
+    mask: Mask,
+    pending_transfer: Option<Transfer>,
+    /// Number of joypads requested with MLT_REQ, to be applied to the joypad
+    players: Option<usize>,
+    /// RGB888 pixels of the picture including the border
+    framebuffer: Vec<u8>,
+}
+
+impl Sgb {
+    /// Create a new Sgb
+    pub fn new() -> Sgb {
+        let mut sgb = Sgb {
+            receiver: PacketReceiver::new(),
+            palettes: [[0x7FFF, 0x56B5, 0x294A, 0x0000]; 4],
+            system_palettes: vec![[0; 4]; 512],
+            attributes: [0; CELLS_X * CELLS_Y],
+            attribute_files: vec![0; ATTRIBUTE_FILES * ATTRIBUTE_FILE_SIZE],
+            border_tiles: vec![0; 256 * 32],
+            border_map: [0; 32 * 32],
+            border_palettes: [[0; 16]; 4],
+            mask: Mask::None,
+            pending_transfer: None,
+            players: None,
+            framebuffer: vec![0; SGB_WIDTH * SGB_HEIGHT * 3],
+        };
+        sgb.render(&vec![0; SCREEN_WIDTH * SCREEN_HEIGHT]);
+        sgb
+    }
+
+    /// RGB888 pixels of the picture including the border, row by row
+    pub fn framebuffer(&self) -> &[u8] {
+        &self.framebuffer
+    }
+
+    /// Returns and clears the number of joypads requested by MLT_REQ
+    pub fn take_players(&mut self) -> Option<usize> {
+        self.players.take()
+    }
+
+    /// Handle a write to P1
+    pub fn write_p1(&mut self, val: u8) {
+        if let Some(data) = self.receiver.write(val) {
+            self.execute(&data);
+        }
+    }
+
+    /// Called at the start of every VBlank with the frame just drawn
+    /// Runs a pending VRAM transfer and renders the picture
+    pub fn vblank(&mut self, ppu: &Ppu) {
+        if let Some(transfer) = self.pending_transfer.take() {
+            self.transfer(transfer, &ppu.vram_transfer_data());
+        }
+        if self.mask != Mask::Freeze {
+            self.render(ppu.shades());
+        }
+    }
+
+    /// Execute a command made of one or more packets
+    fn execute(&mut self, data: &[u8]) {
+        let command = data[0] >> 3;
+        match command {
+            PAL01 => self.set_palette_pair(0, 1, data),
+            PAL23 => self.set_palette_pair(2, 3, data),
+            PAL03 => self.set_palette_pair(0, 3, data),
+            PAL12 => self.set_palette_pair(1, 2, data),
+            ATTR_BLK => self.attr_blk(data),
+            ATTR_LIN => self.attr_lin(data),
+            ATTR_DIV => self.attr_div(data),
+            ATTR_CHR => self.attr_chr(data),
+            PAL_SET => {
+                for i in 0..4 {
+                    let index = u16::from_le_bytes([data[1 + i * 2], data[2 + i * 2]]) & 0x1FF;
+                    self.palettes[i] = self.system_palettes[index as usize];
+                }
+                self.set_shared_colour(self.palettes[0][0]);
+                if data[9] & 0x80 != 0 {
+                    self.apply_attribute_file((data[9] & 0x3F) as usize);
+                }
+                if data[9] & 0x40 != 0 {
+                    self.mask = Mask::None;
+                }
+            }
+            PAL_TRN => self.pending_transfer = Some(Transfer::Palettes),
+            MLT_REQ => {
+                self.players = Some(match data[1] & 0b11 {
+                    0 => 1,
+                    1 => 2,
+                    _ => 4,
+                })
+            }
+            CHR_TRN => self.pending_transfer = Some(Transfer::BorderTiles((data[1] & 1) as usize)),
+            PCT_TRN => self.pending_transfer = Some(Transfer::BorderMap),
+            ATTR_TRN => self.pending_transfer = Some(Transfer::AttributeFiles),
+            ATTR_SET => {
+                self.apply_attribute_file((data[1] & 0x3F) as usize);
+                if data[1] & 0x40 != 0 {
+                    self.mask = Mask::None;
+                }
+            }
+            MASK_EN => {
+                self.mask = match data[1] & 0b11 {
+                    0 => Mask::None,
+                    1 => Mask::Freeze,
+                    2 => Mask::Black,
+                    _ => Mask::Colour0,
+                }
+            }
+            // Sound, SNES program and test commands are not emulated
+            _ => {}
+        }
+    }
+
+    /// Colour 0 is shared by all palettes, the last one written applies to all of them
+    fn set_shared_colour(&mut self, colour: u16) {
+        for palette in self.palettes.iter_mut() {
+            palette[0] = colour;
+        }
+    }
+
+    /// PAL01, PAL23, PAL03 and PAL12: colour 0, then colours 1-3 of both palettes
+    fn set_palette_pair(&mut self, first: usize, second: usize, data: &[u8]) {
+        let colour = |i: usize| u16::from_le_bytes([data[1 + i * 2], data[2 + i * 2]]) & 0x7FFF;
+        self.set_shared_colour(colour(0));
+        for i in 1..4 {
+            self.palettes[first][i] = colour(i);
+            self.palettes[second][i] = colour(i + 3);
+        }
+    }
+
+    /// ATTR_BLK: assign palettes inside, on the border of and outside rectangles of cells
+    fn attr_blk(&mut self, data: &[u8]) {
+        let sets = (data[1] as usize).min(18);
+        for set in data[2..].chunks(6).take(sets) {
+            if set.len() < 6 {
+                break;
+            }
+            let control = set[0];
+            let inside = set[1] & 0b11;
+            let border = (set[1] >> 2) & 0b11;
+            let outside = (set[1] >> 4) & 0b11;
+            // With only the inside or only the outside set, the border takes the same palette
+            let border = match control & 0b111 {
+                0b001 => Some(inside),
+                0b100 => Some(outside),
+                c if c & 0b010 != 0 => Some(border),
+                _ => None,
+            };
+            let (x1, y1, x2, y2) = (
+                set[2] as usize,
+                set[3] as usize,
+                set[4] as usize,
+                set[5] as usize,
+            );
+            for y in 0..CELLS_Y {
+                for x in 0..CELLS_X {
+                    let within = x >= x1 && x <= x2 && y >= y1 && y <= y2;
+                    let on_border = within && (x == x1 || x == x2 || y == y1 || y == y2);
+                    let palette = if on_border {
+                        border
+                    } else if within && control & 0b001 != 0 {
+                        Some(inside)
+                    } else if !within && control & 0b100 != 0 {
+                        Some(outside)
+                    } else {
+                        None
+                    };
+                    if let Some(palette) = palette {
+                        self.attributes[y * CELLS_X + x] = palette;
+                    }
+                }
+            }
+        }
+    }
+
+    /// ATTR_LIN: assign palettes to whole rows or columns of cells
+    fn attr_lin(&mut self, data: &[u8]) {
+        let sets = data[1] as usize;
+        for line in data[2..].iter().take(sets) {
+            let index = (line & 0x1F) as usize;
+            let palette = (line >> 5) & 0b11;
+            if line & 0x80 != 0 {
+                if index < CELLS_Y {
+                    self.attributes[index * CELLS_X..(index + 1) * CELLS_X].fill(palette);
+                }
+            } else if index < CELLS_X {
+                for y in 0..CELLS_Y {
+                    self.attributes[y * CELLS_X + index] = palette;
+                }
+            }
+        }
+    }
+
+    /// ATTR_DIV: split the screen in two at a row or column of cells
+    fn attr_div(&mut self, data: &[u8]) {
+        let after = data[1] & 0b11;
+        let before = (data[1] >> 2) & 0b11;
+        let on = (data[1] >> 4) & 0b11;
+        let horizontal = data[1] & 0x40 != 0;
+        let split = data[2] as usize;
+        for y in 0..CELLS_Y {
+            for x in 0..CELLS_X {
+                let position = if horizontal { y } else { x };
+                self.attributes[y * CELLS_X + x] = match position.cmp(&split) {
+                    std::cmp::Ordering::Less => before,
+                    std::cmp::Ordering::Equal => on,
+                    std::cmp::Ordering::Greater => after,
+                };
+            }
+        }
+    }
+
+    /// ATTR_CHR: assign palettes cell by cell from a starting cell
+    fn attr_chr(&mut self, data: &[u8]) {
+        let (mut x, mut y) = (data[1] as usize, data[2] as usize);
+        let count = u16::from_le_bytes([data[3], data[4]]) as usize;
+        let vertical = data[5] & 1 != 0;
+        for i in 0..count.min(CELLS_X * CELLS_Y) {
+            let Some(byte) = data.get(6 + i / 4) else {
+                break;
+            };
+            if x < CELLS_X && y < CELLS_Y {
+                self.attributes[y * CELLS_X + x] = (byte >> (6 - (i % 4) * 2)) & 0b11;
+            }
+            if vertical {
+                y += 1;
+                if y == CELLS_Y {
+                    y = 0;
+                    x += 1;
+                }
+            } else {
+                x += 1;
+                if x == CELLS_X {
+                    x = 0;
+                    y += 1;
+                }
+            }
+        }
+    }
+
+    /// Apply one of the attribute files sent with ATTR_TRN
+    fn apply_attribute_file(&mut self, file: usize) {
+        if file >= ATTRIBUTE_FILES {
+            return;
+        }
+        let start = file * ATTRIBUTE_FILE_SIZE;
+        for (i, attribute) in self.attributes.iter_mut().enumerate() {
+            let byte = self.attribute_files[start + i / 4];
+            *attribute = (byte >> (6 - (i % 4) * 2)) & 0b11;
+        }
+    }
+
+    /// Store the data of a VRAM transfer
+    fn transfer(&mut self, transfer: Transfer, data: &[u8]) {
+        let word = |i: usize| u16::from_le_bytes([data[i * 2], data[i * 2 + 1]]);
+        match transfer {
+            Transfer::Palettes => {
+                for (i, palette) in self.system_palettes.iter_mut().enumerate() {
+                    *palette = [
+                        word(i * 4),
+                        word(i * 4 + 1),
+                        word(i * 4 + 2),
+                        word(i * 4 + 3),
+                    ];
+                }
+            }
+            Transfer::BorderTiles(half) => {
+                self.border_tiles[half * 0x1000..(half + 1) * 0x1000].copy_from_slice(data);
+            }
+            Transfer::BorderMap => {
+                for (i, entry) in self.border_map.iter_mut().enumerate() {
+                    *entry = word(i);
+                }
+                for (p, palette) in self.border_palettes.iter_mut().enumerate() {
+                    for (c, colour) in palette.iter_mut().enumerate() {
+                        *colour = word(0x400 + p * 16 + c);
+                    }
+                }
+            }
+            Transfer::AttributeFiles => {
+                let len = self.attribute_files.len();
+                self.attribute_files.copy_from_slice(&data[..len]);
+            }
+        }
+    }
+
+    /// Colour index 0-15 of a pixel of a border tile
+    fn border_pixel(&self, tile: usize, row: usize, column: usize) -> usize {
+        let base = tile * 32;
+        let bit = 7 - column;
+        let plane = |offset: usize| ((self.border_tiles[base + offset] >> bit) & 1) as usize;
+        plane(row * 2)
+            | plane(row * 2 + 1) << 1
+            | plane(16 + row * 2) << 2
+            | plane(16 + row * 2 + 1) << 3
+    }
+
+    /// Render the colourized screen inside the border
+    fn render(&mut self, shades: &[u8]) {
+        let backdrop = self.palettes[0][0];
+        for y in 0..SGB_HEIGHT {
+            for x in 0..SGB_WIDTH {
+                let entry = self.border_map[(y / 8) * 32 + x / 8];
+                let row = if entry & 0x8000 != 0 {
+                    7 - y % 8
+                } else {
+                    y % 8
+                };
+                let column = if entry & 0x4000 != 0 {
+                    7 - x % 8
+                } else {
+                    x % 8
+                };
+                let index = self.border_pixel((entry & 0xFF) as usize, row, column);
+                let in_screen = (SCREEN_X..SCREEN_X + SCREEN_WIDTH).contains(&x)
+                    && (SCREEN_Y..SCREEN_Y + SCREEN_HEIGHT).contains(&y);
+                let colour = if index != 0 {
+                    let palette = (((entry >> 10) & 0b111) as usize).saturating_sub(4);
+                    self.border_palettes[palette][index]
+                } else if in_screen {
+                    let (sx, sy) = (x - SCREEN_X, y - SCREEN_Y);
+                    match self.mask {
+                        Mask::Black => 0x0000,
+                        Mask::Colour0 => backdrop,
+                        Mask::None | Mask::Freeze => {
+                            let palette = self.attributes[(sy / 8) * CELLS_X + sx / 8];
+                            let shade = shades[sy * SCREEN_WIDTH + sx];
+                            self.palettes[palette as usize][shade as usize]
+                        }
+                    }
+                } else {
+                    backdrop
+                };
+                let start = (y * SGB_WIDTH + x) * 3;
+                self.framebuffer[start..start + 3]
+                    .copy_from_slice(&rgb555_to_rgb888(colour, false));
+            }
+        }
+    }
+}
+
+impl Default for Sgb {
+    fn default() -> Sgb {
+        Sgb::new()
+    }
+}
+
+impl Savestate for Sgb {
+    fn state(&mut self, state: &mut State) {
+        self.receiver.state(state);
+        for palette in self.palettes.iter_mut().chain(&mut self.system_palettes) {
+            state.u16s(palette);
+        }
+        state.bytes(&mut self.attributes);
+        state.bytes(&mut self.attribute_files);
+        state.bytes(&mut self.border_tiles);
+        state.u16s(&mut self.border_map);
+        for palette in &mut self.border_palettes {
+            state.u16s(palette);
+        }
+        let masks = [Mask::None, Mask::Freeze, Mask::Black, Mask::Colour0];
+        let mut mask = masks.iter().position(|&mask| mask == self.mask).unwrap() as u8;
+        state.u8(&mut mask);
+        state.check((mask as usize) < masks.len(), "SGB mask out of range");
+        self.mask = masks[mask as usize % masks.len()];
+        // Transfers are stored as 0 for none and their position in the list otherwise
+        let transfers = [
+            Transfer::Palettes,
+            Transfer::BorderTiles(0),
+            Transfer::BorderTiles(1),
+            Transfer::BorderMap,
+            Transfer::AttributeFiles,
+        ];
+        let mut transfer = self.pending_transfer.map_or(0, |pending| {
+            transfers
+                .iter()
+                .position(|&transfer| transfer == pending)
+                .unwrap() as u8
+                + 1
+        });
+        state.u8(&mut transfer);
+        state.check(
+            (transfer as usize) <= transfers.len(),
+            "SGB transfer out of range",
+        );
+        self.pending_transfer = transfers.get((transfer as usize).wrapping_sub(1)).copied();
+        let mut players = self.players.unwrap_or(0);
+        state.usize(&mut players);
+        state.check(matches!(players, 0 | 1 | 2 | 4), "SGB players out of range");
+        self.players = Some(players).filter(|&players| players != 0);
+        state.bytes(&mut self.framebuffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::joypad::P1;
+    use super::super::mem::{Memory, ROMAccess};
+    use super::super::rw::RWAccess;
+    use super::super::test_rom::titled_rom;
+    use super::packet::tests::p1_writes;
+    use super::packet::PACKET_SIZE;
+    use super::*;
+
+    /// A single packet command with `args` after the command byte
+    fn packet(command: u8, args: &[u8]) -> [u8; PACKET_SIZE] {
+        let mut data = [0; PACKET_SIZE];
+        data[0] = command << 3 | 1;
+        data[1..1 + args.len()].copy_from_slice(args);
+        data
+    }
+
+    /// RGB888 of the picture at `x`, `y`
+    fn pixel(sgb: &Sgb, x: usize, y: usize) -> [u8; 3] {
+        let start = (y * SGB_WIDTH + x) * 3;
+        sgb.framebuffer()[start..start + 3].try_into().unwrap()
+    }
+
+    #[test]
+    fn pal01_sets_both_palettes_and_the_shared_colour() {
+        let mut sgb = Sgb::new();
+        let colours: [u16; 7] = [0x1234, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0xFFFF];
+        let args: Vec<u8> = colours.iter().flat_map(|c| c.to_le_bytes()).collect();
+        for val in p1_writes(&packet(PAL01, &args)) {
+            sgb.write_p1(val);
+        }
+        assert_eq!(sgb.palettes[0], [0x1234, 0x0001, 0x0002, 0x0003]);
+        assert_eq!(sgb.palettes[1], [0x1234, 0x0004, 0x0005, 0x7FFF]);
+        assert_eq!(sgb.palettes[2][0], 0x1234);
+        assert_eq!(sgb.palettes[3][0], 0x1234);
+    }
+
+    #[test]
+    fn attr_blk_sets_inside_border_and_outside() {
+        let mut sgb = Sgb::new();
+        // One set, all three areas, palettes 1 inside, 2 on the border and 3 outside
+        sgb.execute(&packet(ATTR_BLK, &[1, 0b111, 0b11_10_01, 2, 3, 6, 7]));
+        let attribute = |x: usize, y: usize| sgb.attributes[y * CELLS_X + x];
+        assert_eq!(attribute(3, 4), 1);
+        assert_eq!(attribute(5, 6), 1);
+        assert_eq!(attribute(2, 3), 2);
+        assert_eq!(attribute(6, 5), 2);
+        assert_eq!(attribute(4, 7), 2);
+        assert_eq!(attribute(0, 0), 3);
+        assert_eq!(attribute(7, 4), 3);
+        assert_eq!(attribute(4, 8), 3);
+
+        // Only the inside set, the border takes the inside palette and the outside is kept
+        sgb.execute(&packet(ATTR_BLK, &[1, 0b001, 0b00_00_00, 2, 3, 6, 7]));
+        assert_eq!(sgb.attributes[3 * CELLS_X + 2], 0);
+        assert_eq!(sgb.attributes[4 * CELLS_X + 3], 0);
+        assert_eq!(sgb.attributes[0], 3);
+    }
+
+    #[test]
+    fn mask_en_black_blanks_only_the_screen() {
+        let mut sgb = Sgb::new();
+        let shades = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+        let white = rgb555_to_rgb888(0x7FFF, false);
+        sgb.render(&shades);
+        assert_eq!(pixel(&sgb, SCREEN_X, SCREEN_Y), white);
+
+        sgb.execute(&packet(MASK_EN, &[2]));
+        sgb.render(&shades);
+        assert_eq!(pixel(&sgb, SCREEN_X, SCREEN_Y), [0, 0, 0]);
+        assert_eq!(
+            pixel(
+                &sgb,
+                SCREEN_X + SCREEN_WIDTH - 1,
+                SCREEN_Y + SCREEN_HEIGHT - 1
+            ),
+            [0, 0, 0]
+        );
+        assert_eq!(pixel(&sgb, 0, 0), white);
+
+        sgb.execute(&packet(MASK_EN, &[0]));
+        sgb.render(&shades);
+        assert_eq!(pixel(&sgb, SCREEN_X, SCREEN_Y), white);
+    }
+
+    #[test]
+    fn mlt_req_switches_players() {
+        // An SGB flag of 0x03 and a licensee of 0x33 enable the SGB functions
+        let mut rom = titled_rom(b"TEST", 0x33, &[]);
+        rom[0x146] = 0x03;
+        rom[0x14D] = rom[0x14D].wrapping_sub(0x03);
+        let mut memory = Memory::new();
+        memory.load_rom_data(&rom).unwrap();
+        assert!(memory.sgb().is_some());
+
+        for val in p1_writes(&packet(MLT_REQ, &[0x01])) {
+            memory.write_8(P1, val);
+        }
+        assert_eq!(memory.joypad().players(), 2);
+        // With no line selected the lower nibble reads 0xF minus the player
+        assert_eq!(memory.read_8(P1) & 0x0F, 0x0F);
+        // A rising edge of P15 switches to the next player
+        memory.write_8(P1, 0x10);
+        memory.write_8(P1, 0x30);
+        assert_eq!(memory.read_8(P1) & 0x0F, 0x0E);
+        memory.write_8(P1, 0x10);
+        memory.write_8(P1, 0x30);
+        assert_eq!(memory.read_8(P1) & 0x0F, 0x0F);
+
+        for val in p1_writes(&packet(MLT_REQ, &[0x00])) {
+            memory.write_8(P1, val);
+        }
+        assert_eq!(memory.joypad().players(), 1);
+    }
+}
@@ -0,0 +1,144 @@
+use super::super::state::{Savestate, State};
+
+/// Bytes per command packet
+pub const PACKET_SIZE: usize = 16;
+
+/// Receiver of the command packets a game bit-bangs to the Super Game Boy through P1
+/// Documented at: https://gbdev.io/pandocs/SGB_Command_Packet.html
+///
+/// Every transfer starts with a reset pulse (P14 and P15 low), followed by 128 bits sent least
+/// significant bit first, where a pulse on P14 sends a 0 and a pulse on P15 sends a 1, and ends
+/// with a 0 stop bit. Both lines go high between pulses.
+/// The lower 3 bits of the first byte of a command give its number of packets.
+pub struct PacketReceiver {
+    /// P1 select lines as last written
+    select: u8,
+    /// Whether a reset pulse started a transfer
+    receiving: bool,
+    /// Bits received of the current packet
+    bits: usize,
+    packet: [u8; PACKET_SIZE],
+    /// Packets received of the current command
+    command: Vec<u8>,
+}
+
+impl PacketReceiver {
+    /// Create a new PacketReceiver
+    pub fn new() -> PacketReceiver {
+        PacketReceiver {
+            select: 0x30,
+            receiving: false,
+            bits: 0,
+            packet: [0; PACKET_SIZE],
+            command: Vec::new(),
+        }
+    }
+
+    /// Handle a write to P1, returns the data of every packet of a command once it is complete
+    pub fn write(&mut self, val: u8) -> Option<Vec<u8>> {
+        let select = val & 0x30;
+        let pulse = self.select == 0x30 && select != 0x30;
+        self.select = select;
+        if !pulse {
+            return None;
+        }
+        if select == 0x00 {
+            self.receiving = true;
+            self.bits = 0;
+            self.packet = [0; PACKET_SIZE];
+            return None;
+        }
+        if !self.receiving {
+            return None;
+        }
+        let bit = (select == 0x10) as u8;
+        if self.bits < PACKET_SIZE * 8 {
+            self.packet[self.bits / 8] |= bit << (self.bits % 8);
+            self.bits += 1;
+            return None;
+        }
+        // Stop bit
+        self.receiving = false;
+        if bit != 0 {
+            self.command.clear();
+            return None;
+        }
+        self.command.extend_from_slice(&self.packet);
+        let packets = (self.command[0] & 0b111).max(1) as usize;
+        if self.command.len() >= packets * PACKET_SIZE {
+            return Some(std::mem::take(&mut self.command));
+        }
+        None
+    }
+}
+
+impl Default for PacketReceiver {
+    fn default() -> PacketReceiver {
+        PacketReceiver::new()
+    }
+}
+
+impl Savestate for PacketReceiver {
+    fn state(&mut self, state: &mut State) {
+        state.u8(&mut self.select);
+        state.bool(&mut self.receiving);
+        state.usize(&mut self.bits);
+        state.check(
+            self.bits < PACKET_SIZE * 8 + 1,
+            "SGB packet bit out of range",
+        );
+        state.bytes(&mut self.packet);
+        state.vec(&mut self.command);
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// P1 writes that bit-bang the packets of `data`
+    pub fn p1_writes(data: &[u8]) -> Vec<u8> {
+        let mut writes = Vec::new();
+        for packet in data.chunks(PACKET_SIZE) {
+            writes.extend_from_slice(&[0x00, 0x30]);
+            for i in 0..PACKET_SIZE * 8 {
+                let bit = (packet[i / 8] >> (i % 8)) & 1;
+                writes.extend_from_slice(&[if bit != 0 { 0x10 } else { 0x20 }, 0x30]);
+            }
+            writes.extend_from_slice(&[0x20, 0x30]);
+        }
+        writes
+    }
+
+    #[test]
+    fn receives_bit_banged_packets() {
+        let mut data = [0u8; PACKET_SIZE * 2];
+        data[0] = 0x02;
+        for (i, byte) in data.iter_mut().enumerate().skip(1) {
+            *byte = (i as u8).wrapping_mul(37);
+        }
+        let mut receiver = PacketReceiver::new();
+        let writes = p1_writes(&data);
+        let (last, writes) = writes.split_last().unwrap();
+        let mut received = None;
+        for val in writes {
+            if let Some(command) = receiver.write(*val) {
+                assert!(received.is_none());
+                received = Some(command);
+            }
+        }
+        assert_eq!(receiver.write(*last), None);
+        assert_eq!(received.as_deref(), Some(&data[..]));
+    }
+
+    #[test]
+    fn stop_bit_of_1_drops_the_command() {
+        let mut data = [0u8; PACKET_SIZE];
+        data[0] = 0x01;
+        let mut writes = p1_writes(&data);
+        let stop = writes.len() - 2;
+        writes[stop] = 0x10;
+        let mut receiver = PacketReceiver::new();
+        assert!(writes.iter().all(|val| receiver.write(*val).is_none()));
+    }
+}
@@ -0,0 +1,154 @@
+use std::io;
+
+/// Save states
+/// A state starts with `MAGIC` and `VERSION`, followed by the fields of every component in the
+/// order they are visited, little-endian and without tags. Changing what a component visits
+/// changes the layout, so `VERSION` has to be bumped with it and older states are refused.
+pub const MAGIC: [u8; 4] = *b"GBES";
+pub const VERSION: u32 = 4;
+
+/// A save state being written or read
+/// Components describe their state once by visiting their fields with it. The same visit saves
+/// the fields when saving and overwrites them when loading, so both always agree on the layout.
+pub struct State<'a> {
+    /// Bytes written when saving
+    data: Vec<u8>,
+    /// Bytes left to read when loading
+    input: Option<&'a [u8]>,
+    /// First problem found while loading
+    error: Option<String>,
+}
+
+impl<'a> State<'a> {
+    /// Create a State saving into a new buffer
+    pub fn saver() -> State<'a> {
+        State {
+            data: Vec::new(),
+            input: None,
+            error: None,
+        }
+    }
+
+    /// Create a State loading from `data`
+    pub fn loader(data: &'a [u8]) -> State<'a> {
+        State {
+            data: Vec::new(),
+            input: Some(data),
+            error: None,
+        }
+    }
+
+    /// Whether the fields are being loaded
+    pub fn is_loading(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Record an invalid state unless `valid`, only the first problem is kept
+    pub fn check(&mut self, valid: bool, problem: &str) {
+        if !valid && self.error.is_none() {
+            self.error = Some(problem.to_string());
+        }
+    }
+
+    /// Whether a problem was found while loading
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// The saved bytes, or the first problem found while loading
+    pub fn finish(self) -> io::Result<Vec<u8>> {
+        match self.error {
+            Some(problem) => Err(io::Error::new(io::ErrorKind::InvalidData, problem)),
+            None if self.input.is_some_and(|input| !input.is_empty()) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "trailing bytes after the save state",
+            )),
+            None => Ok(self.data),
+        }
+    }
+
+    pub fn bytes(&mut self, val: &mut [u8]) {
+        match &mut self.input {
+            None => self.data.extend_from_slice(val),
+            Some(input) if input.len() >= val.len() => {
+                let (head, tail) = input.split_at(val.len());
+                val.copy_from_slice(head);
+                *input = tail;
+            }
+            Some(input) => {
+                *input = &[];
+                self.check(false, "save state is truncated");
+            }
+        }
+    }
+
+    pub fn u8(&mut self, val: &mut u8) {
+        self.bytes(std::slice::from_mut(val));
+    }
+
+    pub fn bool(&mut self, val: &mut bool) {
+        let mut byte = *val as u8;
+        self.u8(&mut byte);
+        *val = byte != 0;
+    }
+
+    pub fn u16(&mut self, val: &mut u16) {
+        let mut bytes = val.to_le_bytes();
+        self.bytes(&mut bytes);
+        *val = u16::from_le_bytes(bytes);
+    }
+
+    pub fn u32(&mut self, val: &mut u32) {
+        let mut bytes = val.to_le_bytes();
+        self.bytes(&mut bytes);
+        *val = u32::from_le_bytes(bytes);
+    }
+
+    pub fn u64(&mut self, val: &mut u64) {
+        let mut bytes = val.to_le_bytes();
+        self.bytes(&mut bytes);
+        *val = u64::from_le_bytes(bytes);
+    }
+
+    /// Sizes and indices are stored as 64-bit
+    pub fn usize(&mut self, val: &mut usize) {
+        let mut wide = *val as u64;
+        self.u64(&mut wide);
+        *val = wide as usize;
+    }
+
+    pub fn u16s(&mut self, vals: &mut [u16]) {
+        for val in vals {
+            self.u16(val);
+        }
+    }
+
+    /// A buffer whose length is stored before its bytes
+    pub fn vec(&mut self, val: &mut Vec<u8>) {
+        let mut len = val.len();
+        self.usize(&mut len);
+        if let Some(input) = self.input {
+            // Checked before allocating, the length may be garbage
+            if len > input.len() {
+                self.input = Some(&[]);
+                self.check(false, "save state is truncated");
+                return;
+            }
+            val.resize(len, 0);
+        }
+        self.bytes(val);
+    }
+
+    /// Whether an optional part is present, returns the presence after loading
+    pub fn present(&mut self, present: bool) -> bool {
+        let mut present = present;
+        self.bool(&mut present);
+        present
+    }
+}
+
+/// Components that are part of a save state
+pub trait Savestate {
+    /// Visit every field of the state of the component
+    fn state(&mut self, state: &mut State);
+}
@@ -0,0 +1,76 @@
+use super::interrupt::{Interrupt, IF};
+use super::mem::{Memory, ROMAccess};
+use super::rw::RWAccess;
+use super::serial::SB;
+use super::System;
+
+/// Where the program of a test ROM starts, right after the header
+pub const PROGRAM: usize = 0x150;
+
+/// Build a 32 KiB ROM with a valid header that jumps to `program` at 0x150
+pub fn rom(program: &[u8]) -> Vec<u8> {
+    titled_rom(b"TEST", 0x00, program)
+}
+
+/// Build a ROM with `title` and an old licensee code of `licensee` in its header
+pub fn titled_rom(title: &[u8], licensee: u8, program: &[u8]) -> Vec<u8> {
+    let mut rom = vec![0; 0x8000];
+    // nop; jp $0150
+    rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]);
+    rom[0x134..0x134 + title.len()].copy_from_slice(title);
+    rom[0x14B] = licensee;
+    let mut checksum: u8 = 0;
+    for byte in &rom[0x134..0x14D] {
+        checksum = checksum.wrapping_sub(*byte).wrapping_sub(1);
+    }
+    rom[0x14D] = checksum;
+    rom[PROGRAM..PROGRAM + program.len()].copy_from_slice(program);
+    rom
+}
+
+/// Build a ROM flagged as CGB enhanced, which runs in CGB mode on a CGB
+pub fn cgb_rom(program: &[u8]) -> Vec<u8> {
+    let mut rom = rom(program);
+    set_header(&mut rom, 0x143, 0x80);
+    rom
+}
+
+/// Change a byte of the header, keeping the header checksum valid
+pub fn set_header(rom: &mut [u8], addr: usize, val: u8) {
+    rom[0x14D] = rom[0x14D].wrapping_add(rom[addr]).wrapping_sub(val);
+    rom[addr] = val;
+}
+
+/// A system running `program` from the post-boot state
+pub fn system(program: &[u8]) -> System {
+    let mut memory = Memory::new();
+    memory
+        .load_rom_data(&rom(program))
+        .expect("ERROR: Invalid test ROM");
+    System::new(memory)
+}
+
+/// A system that runs a delay loop `delay` times, then starts a serial transfer of `sb` with `sc`
+pub fn serial_transfer(delay: u16, sb: u8, sc: u8) -> System {
+    let [low, high] = delay.to_le_bytes();
+    system(&[
+        0x01, low, high, // ld bc, delay
+        0x0B, // .loop: dec bc
+        0x78, // ld a, b
+        0xB1, // or c
+        0x20, 0xFB, // jr nz, .loop
+        0x3E, sb, // ld a, sb
+        0xE0, 0x01, // ldh [SB], a
+        0x3E, sc, // ld a, sc
+        0xE0, 0x02, // ldh [SC], a
+        0x18, 0xFE, // jr @
+    ])
+}
+
+/// SB once the serial interrupt was requested
+pub fn serial_result(system: &mut System) -> Option<u8> {
+    let memory = system.memory();
+    memory.sync();
+    let requested = memory.read_8(IF) & 1 << Interrupt::Serial as u8 != 0;
+    requested.then(|| memory.read_8(SB))
+}
@@ -0,0 +1,348 @@
+use super::state::{Savestate, State};
+
+/// Timer registers
+/// Documented at: https://gbdev.io/pandocs/Timer_and_Divider_Registers.html
+pub const DIV: u16 = 0xFF04;
+pub const TIMA: u16 = 0xFF05;
+pub const TMA: u16 = 0xFF06;
+pub const TAC: u16 = 0xFF07;
+
+/// Timer
+/// DIV is the upper byte of a free running 16-bit counter that is incremented every T-cycle.
+/// TIMA is not clocked directly, instead it is incremented on the falling edge of the counter bit
+/// selected by TAC (ANDed with the TAC enable bit). This is why writing to DIV or TAC can cause
+/// spurious increments of TIMA.
+///
+/// When TIMA overflows it reads as 0x00 for one M-cycle, then it is reloaded with TMA and the
+/// timer interrupt is requested. During the M-cycle of the reload, writes to TIMA are ignored and
+/// writes to TMA are also copied into TIMA.
+pub struct Timer {
+    counter: u16,
+    tima: u8,
+    tma: u8,
+    tac: u8,
+    /// T-cycles left until an overflowed TIMA is reloaded with TMA
+    overflow_delay: u8,
+    /// T-cycles left in the M-cycle in which TIMA was reloaded
+    reload_window: u8,
+    interrupt: bool,
+}
+
+impl Timer {
+    /// Create a new Timer
+    /// All registers are initialized to 0
+    pub fn new() -> Timer {
+        Timer {
+            counter: 0,
+            tima: 0,
+            tma: 0,
+            tac: 0,
+            overflow_delay: 0,
+            reload_window: 0,
+            interrupt: false,
+        }
+    }
+
+    /// The internal 16-bit counter, DIV is its upper byte
+    pub fn counter(&self) -> u16 {
+        self.counter
+    }
+
+    /// Set the internal counter, as left by the boot ROM
+    pub fn set_counter(&mut self, counter: u16) {
+        self.counter = counter;
+    }
+
+    /// Bit of the counter selected by TAC
+    /// 00: 4096 Hz (bit 9), 01: 262144 Hz (bit 3), 10: 65536 Hz (bit 5), 11: 16384 Hz (bit 7)
+    fn selected_bit(&self) -> u16 {
+        match self.tac & 0b11 {
+            0b00 => 1 << 9,
+            0b01 => 1 << 3,
+            0b10 => 1 << 5,
+            _ => 1 << 7,
+        }
+    }
+
+    /// The signal that TIMA watches for a falling edge
+    fn signal(&self) -> bool {
+        self.tac & 0b100 != 0 && self.counter & self.selected_bit() != 0
+    }
+
+    /// Increment TIMA, starting the delayed reload on overflow
+    fn increment(&mut self) {
+        let (tima, overflow) = self.tima.overflowing_add(1);
+        self.tima = tima;
+        if overflow {
+            self.overflow_delay = 4;
+        }
+    }
+
+    /// Advance the timer by one T-cycle
+    pub fn tick(&mut self) {
+        if self.reload_window > 0 {
+            self.reload_window -= 1;
+        }
+        if self.overflow_delay > 0 {
+            self.overflow_delay -= 1;
+            if self.overflow_delay == 0 {
+                self.tima = self.tma;
+                self.reload_window = 4;
+                self.interrupt = true;
+            }
+        }
+        let old = self.signal();
+        self.counter = self.counter.wrapping_add(1);
+        if old && !self.signal() {
+            self.increment();
+        }
+    }
+
+    /// T-cycles until TIMA overflows, None while the timer is stopped
+    fn cycles_to_overflow(&self) -> Option<u32> {
+        if self.tac & 0b100 == 0 {
+            return None;
+        }
+        let period = self.selected_bit() as u32 * 2;
+        let edge = period - self.counter as u32 % period;
+        Some(edge + (0xFF - self.tima) as u32 * period)
+    }
+
+    /// T-cycles until the timer interrupt is requested, None while the timer is stopped
+    pub fn cycles_to_interrupt(&self) -> Option<u32> {
+        if self.overflow_delay > 0 {
+            return Some(self.overflow_delay as u32);
+        }
+        self.cycles_to_overflow().map(|cycles| cycles + 4)
+    }
+
+    /// T-cycles until the next falling edge of a bit of the counter
+    pub fn cycles_to_falling_edge(&self, bit: u16) -> u32 {
+        let period = (bit as u32) << 1;
+        period - self.counter as u32 % period
+    }
+
+    /// Advance the timer by `cycles` T-cycles
+    /// The counter and TIMA are advanced at once up to the next overflow, only the reload is
+    /// stepped T-cycle by T-cycle
+    pub fn run(&mut self, mut cycles: u32) {
+        while cycles > 0 {
+            if self.overflow_delay > 0 || self.reload_window > 0 {
+                self.tick();
+                cycles -= 1;
+                continue;
+            }
+            let step = self
+                .cycles_to_overflow()
+                .map_or(cycles, |overflow| overflow.min(cycles));
+            if self.tac & 0b100 != 0 {
+                let period = self.selected_bit() as u32 * 2;
+                let edges = (self.counter as u32 % period + step) / period;
+                if edges > 0 {
+                    self.tima += (edges - 1) as u8;
+                    self.increment();
+                }
+            }
+            self.counter = self.counter.wrapping_add(step as u16);
+            cycles -= step;
+        }
+    }
+
+    /// Returns and clears a pending timer interrupt request
+    pub fn take_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.interrupt)
+    }
+
+    /// Read a timer register
+    pub fn read(&self, addr: u16) -> u8 {
+        match addr {
+            DIV => (self.counter >> 8) as u8,
+            TIMA => self.tima,
+            TMA => self.tma,
+            TAC => self.tac | 0xF8,
+            _ => 0xFF,
+        }
+    }
+
+    /// Write a timer register
+    pub fn write(&mut self, addr: u16, val: u8) {
+        match addr {
+            DIV => {
+                // Any write resets the whole counter, which is a falling edge if the signal was high
+                let old = self.signal();
+                self.counter = 0;
+                if old {
+                    self.increment();
+                }
+            }
+            // Ignored on the reload cycle, otherwise cancels a pending reload
+            TIMA if self.reload_window == 0 => {
+                self.tima = val;
+                self.overflow_delay = 0;
+            }
+            TMA => {
+                self.tma = val;
+                if self.reload_window > 0 {
+                    self.tima = val;
+                }
+            }
+            TAC => {
+                let old = self.signal();
+                self.tac = val & 0b111;
+                if old && !self.signal() {
+                    self.increment();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Timer {
+        Timer::new()
+    }
+}
+
+impl Savestate for Timer {
+    fn state(&mut self, state: &mut State) {
+        state.u16(&mut self.counter);
+        state.u8(&mut self.tima);
+        state.u8(&mut self.tma);
+        state.u8(&mut self.tac);
+        state.u8(&mut self.overflow_delay);
+        state.u8(&mut self.reload_window);
+        state.bool(&mut self.interrupt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Timer counting on bit 3 of the counter, every 16 T-cycles
+    fn fast_timer() -> Timer {
+        let mut timer = Timer::new();
+        timer.write(TAC, 0b101);
+        timer
+    }
+
+    fn ticks(timer: &mut Timer, cycles: u32) {
+        for _ in 0..cycles {
+            timer.tick();
+        }
+    }
+
+    #[test]
+    fn increments_on_falling_edge() {
+        let mut timer = fast_timer();
+        // Bit 3 rises after 8 T-cycles, which does not count
+        ticks(&mut timer, 15);
+        assert_eq!(timer.read(TIMA), 0);
+        ticks(&mut timer, 1);
+        assert_eq!(timer.read(TIMA), 1);
+        ticks(&mut timer, 16 * 10);
+        assert_eq!(timer.read(TIMA), 11);
+    }
+
+    #[test]
+    fn run_matches_ticks() {
+        for tac in 0b100..=0b111 {
+            for cycles in [1, 3, 15, 16, 17, 300, 1024, 5000, 70000] {
+                let mut ticked = Timer::new();
+                let mut run = Timer::new();
+                for timer in [&mut ticked, &mut run] {
+                    timer.write(TMA, 0xF0);
+                    timer.write(TIMA, 0xF8);
+                    timer.write(TAC, tac);
+                }
+                ticks(&mut ticked, cycles);
+                run.run(cycles);
+                assert_eq!(ticked.counter(), run.counter(), "TAC {:b} {}", tac, cycles);
+                assert_eq!(
+                    ticked.read(TIMA),
+                    run.read(TIMA),
+                    "TAC {:b} {}",
+                    tac,
+                    cycles
+                );
+                assert_eq!(ticked.take_interrupt(), run.take_interrupt());
+            }
+        }
+    }
+
+    #[test]
+    fn div_write_increments_when_the_bit_is_high() {
+        let mut timer = fast_timer();
+        ticks(&mut timer, 8);
+        timer.write(DIV, 0x12);
+        assert_eq!(timer.read(TIMA), 1);
+        assert_eq!(timer.counter(), 0);
+
+        // Bit 3 is low, resetting the counter is no edge
+        ticks(&mut timer, 4);
+        timer.write(DIV, 0);
+        assert_eq!(timer.read(TIMA), 1);
+    }
+
+    #[test]
+    fn tac_write_increments_when_the_signal_falls() {
+        let mut timer = fast_timer();
+        ticks(&mut timer, 8);
+        timer.write(TAC, 0b001);
+        assert_eq!(timer.read(TIMA), 1);
+        // Enabling while bit 3 is high is a rising edge
+        timer.write(TAC, 0b101);
+        assert_eq!(timer.read(TIMA), 1);
+        // Bit 9 is low, switching to it from the high bit 3 is a falling edge
+        timer.write(TAC, 0b100);
+        assert_eq!(timer.read(TIMA), 2);
+    }
+
+    /// Timer that overflowed on the last tick, TIMA reloads from 0xAB 4 T-cycles later
+    fn overflowed_timer() -> Timer {
+        let mut timer = fast_timer();
+        timer.write(TMA, 0xAB);
+        timer.write(TIMA, 0xFF);
+        ticks(&mut timer, 16);
+        timer
+    }
+
+    #[test]
+    fn reload_is_delayed_by_an_m_cycle() {
+        let mut timer = overflowed_timer();
+        assert_eq!(timer.read(TIMA), 0x00);
+        ticks(&mut timer, 3);
+        assert_eq!(timer.read(TIMA), 0x00);
+        assert!(!timer.take_interrupt());
+        ticks(&mut timer, 1);
+        assert_eq!(timer.read(TIMA), 0xAB);
+        assert!(timer.take_interrupt());
+    }
+
+    #[test]
+    fn tima_write_before_reload_cancels_it() {
+        let mut timer = overflowed_timer();
+        timer.write(TIMA, 0x42);
+        ticks(&mut timer, 4);
+        assert_eq!(timer.read(TIMA), 0x42);
+        assert!(!timer.take_interrupt());
+    }
+
+    #[test]
+    fn writes_in_the_reload_cycle() {
+        let mut timer = overflowed_timer();
+        ticks(&mut timer, 4);
+        // TIMA writes are ignored, TMA writes go through to TIMA
+        timer.write(TIMA, 0x42);
+        assert_eq!(timer.read(TIMA), 0xAB);
+        timer.write(TMA, 0x37);
+        assert_eq!(timer.read(TIMA), 0x37);
+        // Once the M-cycle is over TIMA is written normally
+        ticks(&mut timer, 4);
+        timer.write(TIMA, 0x42);
+        assert_eq!(timer.read(TIMA), 0x42);
+        timer.write(TMA, 0x11);
+        assert_eq!(timer.read(TIMA), 0x42);
+    }
+}
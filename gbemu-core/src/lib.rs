@@ -0,0 +1,31 @@
+//! Gameboy, Gameboy Color and Super Game Boy emulation
+//!
+//! Load a cartridge into a `Memory`, configure it, then build a `System` to run it:
+//!
+//! ```no_run
+//! use gbemu_core::{Button, Memory, Model, ROMAccess, System};
+//!
+//! let mut memory = Memory::new();
+//! memory.set_model(Model::CGB);
+//! memory.load_rom("game.gb").expect("could not load the cartridge");
+//! let mut system = System::new(memory);
+//! system.press(Button::Start);
+//! system.run_frame();
+//! let pixels = system.framebuffer();
+//! let state = system.save_state();
+//! ```
+//!
+//! The components are reachable through `hardware` for tools that need more than this, and
+//! `Disassembly` turns a ROM into RGBDS assembly.
+
+pub mod disasm;
+pub mod hardware;
+
+pub use disasm::Disassembly;
+pub use hardware::boot::BootRom;
+pub use hardware::compat::CompatPalette;
+pub use hardware::joypad::Button;
+pub use hardware::mem::{Memory, ROMAccess, ROMMetadata};
+pub use hardware::model::Model;
+pub use hardware::pacer::{Pacer, FRAME_RATE};
+pub use hardware::{StopReason, System};
@@ -1,12 +1,176 @@
-mod hardware;
-use hardware::mem::ROMAccess;
-use std::env;
+use gbemu_core::hardware::apu::DEFAULT_SAMPLE_RATE;
+use gbemu_core::hardware::cache::CacheMode;
+use gbemu_core::hardware::serial::link::StdoutLink;
+use gbemu_core::hardware::serial::printer::GameBoyPrinter;
+use gbemu_core::hardware::serial::socket::SocketLink;
+use gbemu_core::{
+    BootRom, CompatPalette, Disassembly, Memory, Model, ROMAccess, StopReason, System,
+};
+use std::io::{self, Write};
+use std::{env, fs, process};
+
+const USAGE: &str = "Usage: gbEMU ROM [OPTIONS]
+       gbEMU disasm ROM [OUTPUT]
+
+Options:
+  --serial-stdout             print bytes sent over the serial port
+  --link-listen ADDR          wait for a link cable from another gbEMU at tcp:HOST:PORT or unix:PATH
+  --link-connect ADDR         connect a link cable to another gbEMU at tcp:HOST:PORT or unix:PATH
+  --printer DIR               Game Boy Printer writing prints as PNG files into DIR
+  --record-audio FILE         record audio to a WAV file
+  --record-stems              with --record-audio, also record each channel to its own WAV file
+  --sample-rate HZ            sample rate of the recorded audio, 44100 by default
+  --frames N                  run N frames as fast as possible instead of in real time, then exit
+  --model MODEL               emulate DMG0, DMG, MGB, SGB, SGB2, CGB0, CGB or AGB
+  --cgb                       same as --model CGB
+  --compat-palette COMBO      override the compatibility palette with a boot button combo
+  --boot-rom FILE             run a boot ROM at startup
+  --instruction-cache MODE    cache decoded instructions in off, rom or ram (the default)";
+
+/// Print the usage and exit with an error
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+/// disasm ROM [OUTPUT]: write the RGBDS disassembly of a ROM to OUTPUT, or print it
+fn disasm(args: &[String]) {
+    let Some(path) = args.first() else { usage() };
+    let rom =
+        fs::read(path).unwrap_or_else(|e| panic!("ERROR: Could not read ROM {}: {}", path, e));
+    let source = Disassembly::new(&rom).to_string();
+    match args.get(1) {
+        Some(output) => fs::write(output, source)
+            .unwrap_or_else(|e| panic!("ERROR: Could not write {}: {}", output, e)),
+        // Stopping early, e.g. piped into head, is not an error
+        None => {
+            let _ = io::stdout().write_all(source.as_bytes());
+        }
+    }
+}
+
+/// Run `frames` frames without pacing, for headless runs and recordings
+fn run_frames(sys: &mut System, frames: u64) -> StopReason {
+    for _ in 0..frames {
+        match sys.run_frame() {
+            StopReason::FrameComplete => {}
+            reason => return reason,
+        }
+    }
+    StopReason::FrameComplete
+}
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let mut memory = hardware::mem::Memory::new();
-    let mut sys = hardware::System::new(&mut memory);
-    memory.load_rom(args[1].as_str());
+    if args.len() < 2 || args[1] == "--help" || args[1] == "-h" {
+        usage();
+    }
+    if args.get(1).is_some_and(|command| command == "disasm") {
+        disasm(&args[2..]);
+        return;
+    }
+    let mut memory = Memory::new();
+    let mut record_audio = None;
+    let mut record_stems = false;
+    let mut sample_rate = DEFAULT_SAMPLE_RATE;
+    let mut frames = None;
+    let mut compat_palette = None;
+    let mut options = args[2..].iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--serial-stdout" => memory.set_serial_link(Box::new(StdoutLink)),
+            "--link-listen" | "--link-connect" => {
+                let addr = options.next().expect("ERROR: Missing link address");
+                let link = SocketLink::open(addr, option == "--link-listen")
+                    .unwrap_or_else(|e| panic!("ERROR: Could not open link {}: {}", addr, e));
+                memory.set_serial_link(Box::new(link));
+            }
+            "--printer" => {
+                let dir = options.next().expect("ERROR: Missing printer directory");
+                let printer = GameBoyPrinter::new(dir.into())
+                    .unwrap_or_else(|e| panic!("ERROR: Could not open printer {}: {}", dir, e));
+                memory.set_serial_link(Box::new(printer));
+            }
+            "--record-audio" => {
+                record_audio = Some(options.next().expect("ERROR: Missing audio file"));
+            }
+            "--record-stems" => record_stems = true,
+            "--sample-rate" => {
+                let rate = options.next().expect("ERROR: Missing sample rate");
+                sample_rate = rate
+                    .parse()
+                    .ok()
+                    .filter(|&rate| rate > 0)
+                    .unwrap_or_else(|| panic!("ERROR: Invalid sample rate {}", rate));
+            }
+            "--frames" => {
+                let count = options.next().expect("ERROR: Missing frame count");
+                frames = Some(
+                    count
+                        .parse()
+                        .unwrap_or_else(|_| panic!("ERROR: Invalid frame count {}", count)),
+                );
+            }
+            "--model" => {
+                let name = options.next().expect("ERROR: Missing model");
+                memory.set_model(
+                    Model::parse(name).unwrap_or_else(|| panic!("ERROR: Unknown model {}", name)),
+                );
+            }
+            "--cgb" => memory.set_model(Model::CGB),
+            "--compat-palette" => {
+                let combo = options.next().expect("ERROR: Missing palette combo");
+                compat_palette = Some(
+                    CompatPalette::parse(combo)
+                        .unwrap_or_else(|| panic!("ERROR: Unknown palette combo {}", combo)),
+                );
+            }
+            "--boot-rom" => {
+                let path = options.next().expect("ERROR: Missing boot ROM file");
+                let boot_rom = BootRom::load(path.as_ref())
+                    .unwrap_or_else(|e| panic!("ERROR: Could not load boot ROM {}: {}", path, e));
+                memory.set_boot_rom(boot_rom);
+            }
+            "--instruction-cache" => {
+                let name = options
+                    .next()
+                    .expect("ERROR: Missing instruction cache mode");
+                memory.set_instruction_cache(
+                    CacheMode::parse(name).unwrap_or_else(|| {
+                        panic!("ERROR: Unknown instruction cache mode {}", name)
+                    }),
+                );
+            }
+            _ => {
+                eprintln!("ERROR: Unknown option {}", option);
+                usage();
+            }
+        }
+    }
+    memory
+        .load_rom(args[1].as_str())
+        .unwrap_or_else(|e| panic!("ERROR: Could not load ROM {}: {}", args[1], e));
+    if let Some(palette) = compat_palette {
+        memory.set_compat_palette(palette);
+    }
+    if let Some(path) = record_audio {
+        memory
+            .apu()
+            .start_recording(path.as_ref(), sample_rate, record_stems)
+            .unwrap_or_else(|e| panic!("ERROR: Could not record audio to {}: {}", path, e));
+    }
     println!("{:?}", memory.get_metadata());
-    sys.run();
+    let mut sys = System::new(memory);
+    let reason = match frames {
+        Some(frames) => run_frames(&mut sys, frames),
+        None => sys.run_realtime(),
+    };
+    eprintln!("Stopped: {:?}", reason);
+    sys.memory().sync();
+    if let Err(e) = sys.memory().apu().stop_recording() {
+        eprintln!("ERROR: Could not finish audio recording: {}", e);
+    }
+    if let Err(e) = sys.memory().finish_serial_link() {
+        eprintln!("ERROR: {}", e);
+    }
 }